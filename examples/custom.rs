@@ -1,3 +1,5 @@
+#![allow(clippy::to_string_in_format_args)]
+
 use ticktime::*;
 
 fn main() {
@@ -15,6 +17,7 @@ fn main() {
                 week_duration: 7,
             },
             compute_events: false,
+            ..Default::default()
         },
     ).unwrap();
 
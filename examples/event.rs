@@ -1,4 +1,5 @@
 use ticktime::*;
+use ticktime::event::TimeUnit;
 
 fn main() {
     // Initialize a lunar ticktime where one tick is 3600 seconds
@@ -6,6 +7,7 @@ fn main() {
         0, TickTimeOptions {
             tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600, month_type: EarthLikeMonthType::Lunar },
             compute_events: true,
+            ..Default::default()
         }).unwrap();
 
     // Calling tick to simulate 29 days and 23 hours
@@ -23,8 +25,18 @@ fn main() {
         minute_update: Some(TicketTimeEventValue { old_value: 0, new_value: 0 }),
         hour_update: Some(TicketTimeEventValue { old_value: 23, new_value: 0 }),
         day_update: Some(TicketTimeEventValue { old_value: 29, new_value: 0 }),
+        weekday_update: Some(TicketTimeEventValue { old_value: 1, new_value: 2 }),
         week_update: None,
         month_update: Some(TicketTimeEventValue { old_value: 0, new_value: 1 }),
-        season_update: None, year_update: None }
+        season_update: None, year_update: None, direction: Forward, alarms: [],
+        before: GameDateTime { year: 0, month: 0, day: 29, hour: 23, minute: 0, second: 0, season: 0, week: 4, weekday: 1 },
+        after: GameDateTime { year: 0, month: 1, day: 0, hour: 0, minute: 0, second: 0, season: 0, week: 4, weekday: 2 },
+        from_tick: 719, to_tick: 720,
+        changed_units: [Hour, Day, Month],
+        crossed_units: [Second, Minute, Hour, Day, Month] }
      */
+
+    // A unit can be crossed without changing: a whole month later, the day is the same
+    let next_month_event = ticktime.advance(24 * 30).unwrap().unwrap();
+    println!("{} {}", next_month_event.crossed(TimeUnit::Day), next_month_event.has_changed(TimeUnit::Day)); // true false
 }
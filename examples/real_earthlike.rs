@@ -1,3 +1,5 @@
+#![allow(clippy::to_string_in_format_args)]
+
 use ticktime::*;
 
 fn main() {
@@ -6,6 +8,7 @@ fn main() {
         0, TickTimeOptions {
             tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600, month_type: EarthLikeMonthType::Real },
             compute_events: false,
+            ..Default::default()
         }).unwrap();
 
     // Calling tick to simulate 40 days
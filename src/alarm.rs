@@ -0,0 +1,183 @@
+use crate::date::GameDateTime;
+use crate::duration::{add_duration_to_date, GameDuration};
+use crate::error::TickTimeError;
+use crate::{complete_date, compute_values, epoch_date, verify_date_values, TickTime};
+
+/// When an alarm fires
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlarmSchedule {
+    /// Once, when the current tick reaches the tick
+    AtTick(usize),
+    /// Once, at the first tick at or after the date
+    AtDate(GameDateTime),
+    /// Repeatedly, each time the duration elapses from the date of the scheduling. The duration
+    /// can't contain months or years.
+    Every(GameDuration),
+    /// Every day at the given time
+    DailyAt {
+        /// Hour of the day
+        hour: usize,
+        /// Minute of the hour
+        minute: usize,
+        /// Second of the minute
+        second: usize,
+    },
+}
+
+/// Identifies an alarm scheduled on a `TickTime`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlarmHandle(usize);
+
+/// An alarm fired by an update of a `TickTime`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FiredAlarm {
+    /// Handle returned when the alarm was scheduled
+    pub handle: AlarmHandle,
+    /// Tick at which the alarm fired
+    pub tick: usize,
+    /// Date of the alarm: the scheduled date, or the date of the tick for `AlarmSchedule::AtTick`
+    pub date: GameDateTime,
+}
+
+/// A scheduled alarm with its next occurrence
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Alarm {
+    handle: AlarmHandle,
+    schedule: AlarmSchedule,
+    /// Next date of a date based alarm, unused by `AlarmSchedule::AtTick`
+    next_date: GameDateTime,
+}
+
+/// Alarms scheduled on a `TickTime`, in the order of scheduling
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct AlarmScheduler {
+    /// Identifier given to the next alarm
+    next_id: usize,
+    alarms: Vec<Alarm>,
+}
+
+impl AlarmScheduler {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.alarms.is_empty()
+    }
+}
+
+impl TickTime {
+    /// Schedule an alarm. Each update moving the current tick forward returns the alarms fired
+    /// since the previous tick in its event, even when `compute_events` is disabled, in
+    /// chronological order. Every occurrence is returned, even during large jumps. An alarm whose
+    /// time has already passed fires on the next update, and moving backward doesn't fire nor
+    /// reschedule alarms.
+    pub fn schedule_alarm(&mut self, schedule: AlarmSchedule) -> Result<AlarmHandle, TickTimeError> {
+        self.verify_schedule(&schedule)?;
+        let now = self.now();
+        let next_date = match schedule {
+            AlarmSchedule::AtTick(_) => now,
+            AlarmSchedule::AtDate(date) => complete_date(&self.options, &date)?,
+            AlarmSchedule::Every(interval) => add_duration_to_date(&self.options, &now, &interval)?,
+            AlarmSchedule::DailyAt { hour, minute, second } => {
+                let date = GameDateTime { hour, minute, second, ..now };
+                if date <= now { add_duration_to_date(&self.options, &date, &GameDuration::days(1))? } else { date }
+            }
+        };
+        let handle = AlarmHandle(self.alarms.next_id);
+        self.alarms.next_id += 1;
+        self.alarms.alarms.push(Alarm { handle, schedule, next_date });
+        Ok(handle)
+    }
+
+    /// Restore the alarms of a save, rejecting the ones `schedule_alarm` would reject
+    #[cfg(feature = "serde")]
+    pub(crate) fn restore_alarms(&mut self, mut alarms: AlarmScheduler) -> Result<(), TickTimeError> {
+        for alarm in alarms.alarms.iter_mut() {
+            self.verify_schedule(&alarm.schedule)?;
+            if !matches!(alarm.schedule, AlarmSchedule::AtTick(_)) {
+                self.verify_alarm_date(&alarm.next_date)?;
+                alarm.next_date = complete_date(&self.options, &alarm.next_date)?;
+            }
+        }
+        self.alarms = alarms;
+        Ok(())
+    }
+
+    /// Check the values of a schedule, for the new and the restored alarms
+    fn verify_schedule(&self, schedule: &AlarmSchedule) -> Result<(), TickTimeError> {
+        match *schedule {
+            AlarmSchedule::AtTick(_) => Ok(()),
+            AlarmSchedule::AtDate(date) => self.verify_alarm_date(&date),
+            AlarmSchedule::Every(interval) => {
+                if interval.years > 0 || interval.months > 0 {
+                    return Err(TickTimeError::CalendarDependentDuration);
+                }
+                if interval == GameDuration::default() {
+                    Err(TickTimeError::ZeroAlarmInterval)
+                } else {
+                    Ok(())
+                }
+            }
+            AlarmSchedule::DailyAt { hour, minute, second } => {
+                verify_date_values(&self.options.tick_time_type, &GameDateTime { hour, minute, second, ..Default::default() })
+            }
+        }
+    }
+
+    /// Check that the date of an alarm exists and can be reached from the epoch
+    fn verify_alarm_date(&self, date: &GameDateTime) -> Result<(), TickTimeError> {
+        verify_date_values(&self.options.tick_time_type, date)?;
+        if *date < epoch_date(&self.options.epoch) {
+            return Err(TickTimeError::DateBeforeEpoch);
+        }
+        Ok(())
+    }
+
+    /// Remove a scheduled alarm. Returns false if it was already cancelled or fired once.
+    pub fn cancel_alarm(&mut self, handle: AlarmHandle) -> bool {
+        let alarms = &mut self.alarms.alarms;
+        let count = alarms.len();
+        alarms.retain(|alarm| alarm.handle != handle);
+        alarms.len() != count
+    }
+
+    /// Fire the alarms due at or before `tick` in chronological order, removing the one-shot ones
+    pub(crate) fn fire_alarms(&mut self, tick: usize) -> Vec<FiredAlarm> {
+        let mut alarms = std::mem::take(&mut self.alarms.alarms);
+        let mut fired = vec![];
+        alarms.retain_mut(|alarm| {
+            if let AlarmSchedule::AtTick(alarm_tick) = alarm.schedule {
+                if alarm_tick > tick {
+                    return true;
+                }
+                let total_seconds = self.tick_total_seconds(alarm_tick).expect("The ticks before the current one are counted");
+                let date = compute_values(&self.options, total_seconds).date();
+                fired.push(FiredAlarm { handle: alarm.handle, tick: alarm_tick, date });
+                return false;
+            }
+            // A date never reached with the current time scale stays scheduled
+            while let Ok(alarm_tick) = self.tick_for(&alarm.next_date) {
+                if alarm_tick > tick {
+                    break;
+                }
+                fired.push(FiredAlarm { handle: alarm.handle, tick: alarm_tick, date: alarm.next_date });
+                let next_date = match alarm.schedule {
+                    AlarmSchedule::Every(interval) => add_duration_to_date(&self.options, &alarm.next_date, &interval),
+                    AlarmSchedule::DailyAt { .. } => add_duration_to_date(&self.options, &alarm.next_date, &GameDuration::days(1)),
+                    _ => return false,
+                };
+                // An alarm whose next date can't be counted or doesn't move forward never fires again
+                match next_date {
+                    Ok(next_date) if next_date > alarm.next_date => alarm.next_date = next_date,
+                    _ => return false,
+                }
+            }
+            true
+        });
+        self.alarms.alarms = alarms;
+        fired.sort_by_key(|alarm| (alarm.tick, alarm.date, alarm.handle.0));
+        fired
+    }
+}
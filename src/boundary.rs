@@ -0,0 +1,129 @@
+use crate::date::GameDateTime;
+use crate::event::TimeUnit;
+use crate::{
+    compute_values, get_days_before_year, get_hours_in_a_day, get_months_durations_of_year, get_season_duration,
+    get_total_seconds, get_week_duration, is_leap_year, EarthLikeMonthType, TickTime, TickTimeOptions, TickTimeType,
+    LUNAR_YEAR_DURATION,
+};
+
+/// Days of the year on which the season changes, in increasing order
+pub(crate) fn season_boundaries(options: &TickTimeOptions, year: usize) -> Vec<usize> {
+    match &options.tick_time_type {
+        // The last days of the year belong to the season starting the next year
+        TickTimeType::EarthLike { month_type: EarthLikeMonthType::Real, .. } => get_season_duration(is_leap_year(year))
+            .iter()
+            .scan(0, |day, duration| {
+                *day += duration;
+                Some(*day)
+            })
+            .collect(),
+        TickTimeType::EarthLike { month_type: EarthLikeMonthType::Lunar, .. } => {
+            (0..4).map(|season| season * LUNAR_YEAR_DURATION / 4).collect()
+        }
+        TickTimeType::Custom { seasons_durations, .. } if seasons_durations.len() > 1 => {
+            let year_duration: usize = seasons_durations.iter().sum();
+            let mut boundaries: Vec<usize> = seasons_durations
+                .iter()
+                .scan(options.seasons_offset, |day, duration| {
+                    let boundary = *day % year_duration;
+                    *day += duration;
+                    Some(boundary)
+                })
+                .collect();
+            boundaries.sort_unstable();
+            boundaries
+        }
+        // A single season never changes
+        TickTimeType::Custom { .. } => vec![],
+    }
+}
+
+impl TickTime {
+    /// Number of ticks until the earliest tick at or after the next boundary of the given unit,
+    /// like the start of the next day for `TimeUnit::Day`, computed from the calendar without
+    /// ticking. The value of the unit is not always different at that tick: with a tick of 60
+    /// seconds, the next second boundary is reached by the next tick although the second stays 0.
+    /// The day of the week changes with the day, so `TimeUnit::Day` covers it, and milliseconds
+    /// have no unit. Returns None when the unit has no next boundary, like the season of a
+    /// calendar with a single season, or when the time scale is 0.
+    pub fn ticks_until_next(&self, unit: TimeUnit) -> Option<usize> {
+        self.ticks_until_next_at(self.current_tick, unit)
+    }
+
+    /// Number of ticks from `tick` until the next boundary of the given unit
+    pub(crate) fn ticks_until_next_at(&self, tick: usize, unit: TimeUnit) -> Option<usize> {
+        let tick_time_type = &self.options.tick_time_type;
+        let day_seconds = get_hours_in_a_day(tick_time_type) * 3600;
+        let total_seconds = self.tick_total_seconds(tick).ok()?;
+        let values = compute_values(&self.options, total_seconds);
+        let total_days = total_seconds / day_seconds;
+        let year = values.year;
+        let year_start = get_days_before_year(tick_time_type, year)?;
+        let next_year_start = get_days_before_year(tick_time_type, year + 1)?;
+        let day_in_year = total_days - year_start;
+
+        let boundary_seconds = match unit {
+            TimeUnit::Second => total_seconds + 1,
+            TimeUnit::Minute => (total_seconds / 60 + 1) * 60,
+            TimeUnit::Hour => (total_seconds / 3600 + 1) * 3600,
+            TimeUnit::Day => (total_days + 1) * day_seconds,
+            // Weeks are counted from the start of each year
+            TimeUnit::Week => {
+                let week_duration = get_week_duration(tick_time_type);
+                (year_start + (day_in_year / week_duration + 1) * week_duration).min(next_year_start) * day_seconds
+            }
+            TimeUnit::Month => {
+                let months_durations = get_months_durations_of_year(tick_time_type, year);
+                (year_start + months_durations[..=values.month].iter().sum::<usize>()) * day_seconds
+            }
+            TimeUnit::Season => {
+                let boundary = season_boundaries(&self.options, year)
+                    .into_iter()
+                    .find(|boundary| *boundary > day_in_year)
+                    .map(|boundary| year_start + boundary)
+                    .or_else(|| season_boundaries(&self.options, year + 1).first().map(|boundary| next_year_start + boundary))?;
+                boundary * day_seconds
+            }
+            TimeUnit::Year => next_year_start * day_seconds,
+        };
+
+        let boundary = compute_values(&self.options, boundary_seconds).date();
+        self.tick_for(&boundary).ok().map(|boundary_tick| boundary_tick - tick)
+    }
+
+    /// Units of which a boundary is between two dates of the calendar, `first` being at or before
+    /// `last`. Compares the number of units elapsed since the beginning of the calendar, or since
+    /// the start of the year for the units counted from it, without searching the boundaries.
+    pub(crate) fn crossed_units(&self, first: &GameDateTime, last: &GameDateTime) -> Vec<TimeUnit> {
+        let tick_time_type = &self.options.tick_time_type;
+        let total_seconds = |date| get_total_seconds(tick_time_type, date).expect("The dates of the ticks can be counted");
+        let (first_seconds, last_seconds) = (total_seconds(first), total_seconds(last));
+        let day_seconds = get_hours_in_a_day(tick_time_type) * 3600;
+        let (first_days, last_days) = (first_seconds / day_seconds, last_seconds / day_seconds);
+        TimeUnit::ALL
+            .iter()
+            .copied()
+            .filter(|unit| match unit {
+                TimeUnit::Second => last_seconds > first_seconds,
+                TimeUnit::Minute => last_seconds / 60 > first_seconds / 60,
+                TimeUnit::Hour => last_seconds / 3600 > first_seconds / 3600,
+                TimeUnit::Day => last_days > first_days,
+                TimeUnit::Week => (last.year, last.week) > (first.year, first.week),
+                TimeUnit::Month => (last.year, last.month) > (first.year, first.month),
+                // Seasons start with a day, the boundaries are only counted when a day starts
+                TimeUnit::Season => {
+                    last_days > first_days && self.started_seasons(last_days, last.year) > self.started_seasons(first_days, first.year)
+                }
+                TimeUnit::Year => last.year > first.year,
+            })
+            .collect()
+    }
+
+    /// Number of seasons started between the beginning of the calendar and the day `total_days` of the year
+    fn started_seasons(&self, total_days: usize, year: usize) -> usize {
+        let year_start = get_days_before_year(&self.options.tick_time_type, year).expect("The year of a date can be counted");
+        // Each year has the same number of season boundaries
+        let boundaries = season_boundaries(&self.options, year);
+        year * boundaries.len() + boundaries.iter().filter(|boundary| **boundary <= total_days - year_start).count()
+    }
+}
@@ -0,0 +1,77 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Formatter;
+use std::hash::{Hash, Hasher};
+
+/// A date and time of the in game calendar. Each value is zero based.
+/// Dates are ordered chronologically, from the year to the second.
+/// The season, week and weekday are computed from the date: they are filled in the dates
+/// returned by a `TickTime` and ignored by the methods taking a date, by the comparisons and by the hash.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameDateTime {
+    /// Year of the date
+    pub year: usize,
+    /// Month of the date, in the year
+    pub month: usize,
+    /// Day of the date, in the month
+    pub day: usize,
+    /// Hour of the date
+    pub hour: usize,
+    /// Minute of the date
+    pub minute: usize,
+    /// Second of the date
+    pub second: usize,
+    /// Season of the date
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub season: usize,
+    /// Week of the date, in the year
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub week: usize,
+    /// Day of the week of the date
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub weekday: usize,
+}
+
+impl GameDateTime {
+    /// Values identifying the date, from the year to the second
+    fn calendar_values(&self) -> (usize, usize, usize, usize, usize, usize) {
+        (self.year, self.month, self.day, self.hour, self.minute, self.second)
+    }
+}
+
+impl PartialEq for GameDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.calendar_values() == other.calendar_values()
+    }
+}
+
+impl Eq for GameDateTime {}
+
+impl PartialOrd for GameDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GameDateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.calendar_values().cmp(&other.calendar_values())
+    }
+}
+
+impl Hash for GameDateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.calendar_values().hash(state);
+    }
+}
+
+impl fmt::Display for GameDateTime {
+    /// Display the zero based values, like `Year 3, Month 5, Day 12, 08:00:09`
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f, "Year {}, Month {}, Day {}, {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use crate::error::TickTimeError;
+use crate::event::TickTimeEvent;
+use crate::TickTime;
+
+/// Drives a `TickTime` from the real time elapsed between frames, with a fixed timestep.
+/// The elapsed time is accumulated and a tick is applied for each full tick duration, up to
+/// `max_steps_per_frame` ticks per frame.
+#[derive(Clone, Debug)]
+pub struct TickDriver {
+    /// Real time duration of a tick
+    tick_duration: Duration,
+    /// Maximum number of ticks applied by a single update
+    max_steps_per_frame: usize,
+    /// Real time elapsed and not yet converted into ticks
+    accumulator: Duration,
+}
+
+impl TickDriver {
+    /// Create a driver applying a tick every `tick_duration` of real time
+    pub fn new(tick_duration: Duration, max_steps_per_frame: usize) -> Result<Self, TickTimeError> {
+        if tick_duration.is_zero() {
+            return Err(TickTimeError::ZeroTickDuration);
+        }
+        if max_steps_per_frame == 0 {
+            return Err(TickTimeError::ZeroMaxStepsPerFrame);
+        }
+        Ok(TickDriver { tick_duration, max_steps_per_frame, accumulator: Duration::ZERO })
+    }
+
+    /// Create a driver applying `ticks_per_second` ticks every real second
+    pub fn with_ticks_per_second(ticks_per_second: u32, max_steps_per_frame: usize) -> Result<Self, TickTimeError> {
+        if ticks_per_second == 0 {
+            return Err(TickTimeError::ZeroTickDuration);
+        }
+        TickDriver::new(Duration::from_secs(1) / ticks_per_second, max_steps_per_frame)
+    }
+
+    /// Add the real time elapsed since the last frame and tick `tick_time` once per full tick
+    /// duration accumulated. When more than `max_steps_per_frame` ticks are due, the late ticks
+    /// are dropped so a slow frame doesn't slow down the following ones.
+    /// Returns the events of every tick merged into one, None when no event was computed.
+    pub fn update(&mut self, tick_time: &mut TickTime, delta: Duration) -> Option<TickTimeEvent> {
+        let (accumulated, tick_duration) = ((self.accumulator + delta).as_nanos(), self.tick_duration.as_nanos());
+        let steps = (accumulated / tick_duration).min(self.max_steps_per_frame as u128) as usize;
+        self.accumulator = Duration::from_nanos((accumulated % tick_duration) as u64);
+
+        (0..steps).fold(None, |events: Option<TickTimeEvent>, _| match (events, tick_time.tick()) {
+            (Some(events), Some(event)) => Some(events.merge(event)),
+            (events, event) => events.or(event),
+        })
+    }
+
+    /// Real time accumulated and not yet converted into a tick, lower than the tick duration
+    pub fn accumulated(&self) -> Duration {
+        self.accumulator
+    }
+
+    /// Progress towards the next tick, from 0 to 1, to interpolate rendering between two ticks
+    pub fn alpha(&self) -> f64 {
+        self.accumulator.as_secs_f64() / self.tick_duration.as_secs_f64()
+    }
+
+    /// Return the real time duration of a tick
+    pub fn tick_duration(&self) -> Duration {
+        self.tick_duration
+    }
+
+    /// Return the maximum number of ticks applied by a single update
+    pub fn max_steps_per_frame(&self) -> usize {
+        self.max_steps_per_frame
+    }
+}
@@ -0,0 +1,195 @@
+use std::ops::Add;
+
+use crate::date::GameDateTime;
+use crate::error::TickTimeError;
+use crate::{
+    complete_date, compute_values, get_hours_in_a_day, get_months_durations_of_year, get_total_seconds, get_week_duration,
+    ticks_for_milliseconds, verify_date_values, verify_options, TickTime, TickTimeOptions, TickTimeType,
+};
+
+/// An amount of in game time, expressed in calendar units.
+/// Weeks, days and hours follow the `week_duration` and `hours_in_a_day` of the calendar.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameDuration {
+    /// Number of years
+    pub years: usize,
+    /// Number of months
+    pub months: usize,
+    /// Number of weeks
+    pub weeks: usize,
+    /// Number of days
+    pub days: usize,
+    /// Number of hours
+    pub hours: usize,
+    /// Number of minutes
+    pub minutes: usize,
+    /// Number of seconds
+    pub seconds: usize,
+}
+
+impl GameDuration {
+    /// A duration of `years` years
+    pub fn years(years: usize) -> Self {
+        GameDuration { years, ..Default::default() }
+    }
+
+    /// A duration of `months` months
+    pub fn months(months: usize) -> Self {
+        GameDuration { months, ..Default::default() }
+    }
+
+    /// A duration of `weeks` weeks
+    pub fn weeks(weeks: usize) -> Self {
+        GameDuration { weeks, ..Default::default() }
+    }
+
+    /// A duration of `days` days
+    pub fn days(days: usize) -> Self {
+        GameDuration { days, ..Default::default() }
+    }
+
+    /// A duration of `hours` hours
+    pub fn hours(hours: usize) -> Self {
+        GameDuration { hours, ..Default::default() }
+    }
+
+    /// A duration of `minutes` minutes
+    pub fn minutes(minutes: usize) -> Self {
+        GameDuration { minutes, ..Default::default() }
+    }
+
+    /// A duration of `seconds` seconds
+    pub fn seconds(seconds: usize) -> Self {
+        GameDuration { seconds, ..Default::default() }
+    }
+
+    /// Number of ticks needed to cover this duration at the time scale 1, rounded up. Months and years have no fixed
+    /// length, a duration containing them returns an error: use `TickTime::ticks_in` instead.
+    pub fn to_ticks(&self, options: &TickTimeOptions) -> Result<usize, TickTimeError> {
+        verify_options(options)?;
+        if self.years > 0 || self.months > 0 {
+            return Err(TickTimeError::CalendarDependentDuration);
+        }
+        ticks_for_milliseconds(options, self.fixed_seconds(&options.tick_time_type)? as u128 * 1000)
+    }
+
+    /// Number of seconds of the weeks, days, hours, minutes and seconds of the duration
+    fn fixed_seconds(&self, tick_time_type: &TickTimeType) -> Result<usize, TickTimeError> {
+        let hours_in_a_day = get_hours_in_a_day(tick_time_type);
+        self.weeks
+            .checked_mul(get_week_duration(tick_time_type))
+            .and_then(|days| days.checked_add(self.days))
+            .and_then(|days| days.checked_mul(hours_in_a_day))
+            .and_then(|hours| hours.checked_add(self.hours))
+            .and_then(|hours| hours.checked_mul(3600))
+            .and_then(|seconds| seconds.checked_add(self.minutes.checked_mul(60)?))
+            .and_then(|seconds| seconds.checked_add(self.seconds))
+            .ok_or(TickTimeError::TickOverflow)
+    }
+}
+
+impl Add for GameDuration {
+    type Output = GameDuration;
+
+    fn add(self, other: GameDuration) -> GameDuration {
+        GameDuration {
+            years: self.years + other.years,
+            months: self.months + other.months,
+            weeks: self.weeks + other.weeks,
+            days: self.days + other.days,
+            hours: self.hours + other.hours,
+            minutes: self.minutes + other.minutes,
+            seconds: self.seconds + other.seconds,
+        }
+    }
+}
+
+/// Add a duration to a valid date. Years and months are added first, the day being clamped to the
+/// last day of the resulting month, then the fixed units are added. Returns an error when the
+/// resulting date is too far to be counted.
+pub(crate) fn add_duration_to_date(
+    options: &TickTimeOptions,
+    date: &GameDateTime,
+    duration: &GameDuration,
+) -> Result<GameDateTime, TickTimeError> {
+    let tick_time_type = &options.tick_time_type;
+    let months_in_year = get_months_durations_of_year(tick_time_type, date.year).len();
+    let total_months = date.month.checked_add(duration.months).ok_or(TickTimeError::TickOverflow)?;
+    let year = date.year
+        .checked_add(duration.years)
+        .and_then(|year| year.checked_add(total_months / months_in_year))
+        .ok_or(TickTimeError::TickOverflow)?;
+    let month = total_months % months_in_year;
+    let day = date.day.min(get_months_durations_of_year(tick_time_type, year)[month] - 1);
+
+    let total_seconds = get_total_seconds(tick_time_type, &GameDateTime { year, month, day, ..*date })?
+        .checked_add(duration.fixed_seconds(tick_time_type)?)
+        .ok_or(TickTimeError::TickOverflow)?;
+    Ok(compute_values(options, total_seconds).date())
+}
+
+/// Breakdown of the time between two valid dates, `from` being before or at `to`
+fn duration_between_dates(options: &TickTimeOptions, from: &GameDateTime, to: &GameDateTime) -> Result<GameDuration, TickTimeError> {
+    let tick_time_type = &options.tick_time_type;
+    let add = |duration: &GameDuration| add_duration_to_date(options, from, duration);
+
+    let mut duration = GameDuration::years(to.year - from.year);
+    while duration.years > 0 && add(&duration)? > *to {
+        duration.years -= 1;
+    }
+
+    let months_in_year = get_months_durations_of_year(tick_time_type, from.year).len();
+    let base = add(&duration)?;
+    duration.months = (to.year - base.year) * months_in_year + to.month - base.month;
+    while duration.months > 0 && add(&duration)? > *to {
+        duration.months -= 1;
+    }
+
+    let mut remaining_seconds = get_total_seconds(tick_time_type, to)? - get_total_seconds(tick_time_type, &add(&duration)?)?;
+    let day_seconds = get_hours_in_a_day(tick_time_type) * 3600;
+    duration.days = remaining_seconds / day_seconds;
+    remaining_seconds %= day_seconds;
+    duration.hours = remaining_seconds / 3600;
+    duration.minutes = remaining_seconds % 3600 / 60;
+    duration.seconds = remaining_seconds % 60;
+    Ok(duration)
+}
+
+impl TickTime {
+    /// Time between two dates in calendar units: years, then months, then days, hours, minutes
+    /// and seconds. Weeks are counted as days. The order of the dates doesn't matter.
+    pub fn duration_between(&self, a: &GameDateTime, b: &GameDateTime) -> Result<GameDuration, TickTimeError> {
+        let (a, b) = (complete_date(&self.options, a)?, complete_date(&self.options, b)?);
+        duration_between_dates(&self.options, &a.min(b), &a.max(b))
+    }
+
+    /// Time between the dates of two ticks in calendar units, see `TickTime::duration_between`.
+    /// Returns an error when the date of a tick is too far to be counted.
+    pub fn duration_between_ticks(&self, a: usize, b: usize) -> Result<GameDuration, TickTimeError> {
+        let date_at = |tick| self.tick_total_seconds(tick).map(|total_seconds| compute_values(&self.options, total_seconds).date());
+        duration_between_dates(&self.options, &date_at(a.min(b))?, &date_at(a.max(b))?)
+    }
+
+    /// Time between the current dates of two `TickTime` in calendar units, see
+    /// `TickTime::duration_between`. Each date follows the time scale of its own `TickTime`, the
+    /// calendar of `a` is used to count the time between them.
+    pub fn between(a: &TickTime, b: &TickTime) -> GameDuration {
+        let (a_now, b_now) = (a.now(), b.now());
+        duration_between_dates(&a.options, &a_now.min(b_now), &a_now.max(b_now)).expect("The current dates can be counted")
+    }
+
+    /// Add a duration to a date, following the calendar: adding a month to the last day of a
+    /// month returns the last day of the next month when it is shorter.
+    pub fn add_duration(&self, date: &GameDateTime, duration: &GameDuration) -> Result<GameDateTime, TickTimeError> {
+        verify_date_values(&self.options.tick_time_type, date)?;
+        add_duration_to_date(&self.options, date, duration)
+    }
+
+    /// Number of ticks from the current tick to the earliest tick at or after the current date
+    /// plus the given duration, following the time scale. Returns an error when the time scale is 0.
+    pub fn ticks_in(&self, duration: &GameDuration) -> Result<usize, TickTimeError> {
+        let date = add_duration_to_date(&self.options, &self.now(), duration)?;
+        Ok(self.tick_for(&date)?.saturating_sub(self.current_tick))
+    }
+}
@@ -0,0 +1,209 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Errors returned when a `TickTime` can't be created or updated
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TickTimeError {
+    /// `seconds_per_tick` is 0, the minimum value is 1
+    ZeroSecondsPerTick,
+    /// A value of the `tick_rate` is 0, the minimum value for both is 1
+    ZeroTickRate,
+    /// The sum of `months_durations` and the sum of `seasons_durations` are different
+    MonthSeasonSumMismatch {
+        /// Sum of the months durations
+        months_total: usize,
+        /// Sum of the seasons durations
+        seasons_total: usize,
+    },
+    /// `months_durations` is empty
+    EmptyMonths,
+    /// A month of `months_durations` has no day
+    ZeroMonthDuration {
+        /// Index of the empty month
+        month: usize,
+    },
+    /// `seasons_durations` is empty
+    EmptySeasons,
+    /// A season of `seasons_durations` has no day
+    ZeroSeasonDuration {
+        /// Index of the empty season
+        season: usize,
+    },
+    /// `seasons_offset` is not a day of the year
+    SeasonsOffsetOutOfRange {
+        /// The configured seasons offset
+        seasons_offset: usize,
+        /// Number of days in a year
+        year_duration: usize,
+    },
+    /// `hours_in_a_day` is 0
+    ZeroHoursInDay,
+    /// `week_duration` is 0
+    ZeroWeekDuration,
+    /// The number of month names doesn't match the number of months
+    MonthNamesCountMismatch {
+        /// Number of months in the calendar
+        expected: usize,
+        /// Number of configured names
+        actual: usize,
+    },
+    /// The number of season names doesn't match the number of seasons
+    SeasonNamesCountMismatch {
+        /// Number of seasons in the calendar
+        expected: usize,
+        /// Number of configured names
+        actual: usize,
+    },
+    /// The number of weekday names doesn't match the week duration
+    WeekdayNamesCountMismatch {
+        /// Number of days in a week
+        expected: usize,
+        /// Number of configured names
+        actual: usize,
+    },
+    /// A date, like the epoch, refers to a month that doesn't exist in the calendar
+    MonthOutOfRange {
+        /// The month of the date
+        month: usize,
+        /// Number of months in the year of the date
+        months_in_year: usize,
+    },
+    /// A date, like the epoch, refers to a day that doesn't exist in its month
+    DayOutOfRange {
+        /// The day of the date
+        day: usize,
+        /// Number of days in the month of the date
+        days_in_month: usize,
+    },
+    /// A date, like the epoch, refers to a day of the week that doesn't exist
+    WeekdayOutOfRange {
+        /// The day of the week of the date
+        weekday: usize,
+        /// Number of days in a week
+        week_duration: usize,
+    },
+    /// A date, like the epoch, refers to an hour that doesn't exist in a day
+    HourOutOfRange {
+        /// The hour of the date
+        hour: usize,
+        /// Number of hours in a day
+        hours_in_a_day: usize,
+    },
+    /// A date, like the epoch, has a minute of 60 or more
+    MinuteOutOfRange {
+        /// The minute of the date
+        minute: usize,
+    },
+    /// A date, like the epoch, has a second of 60 or more
+    SecondOutOfRange {
+        /// The second of the date
+        second: usize,
+    },
+    /// The date is before the epoch, so no tick can represent it
+    DateBeforeEpoch,
+    /// The date or duration is too far from the beginning of the calendar to be represented by a tick
+    TickOverflow,
+    /// The parsed input doesn't follow the pattern
+    ParseMismatch {
+        /// Byte position of the first unexpected character in the input
+        position: usize,
+    },
+    /// A parsed week, weekday or season doesn't match the parsed date
+    InconsistentDate {
+        /// Byte position of the inconsistent value in the input
+        position: usize,
+    },
+    /// The duration contains months or years, which have no fixed length
+    CalendarDependentDuration,
+    /// The denominator of a `TimeScale` is 0
+    ZeroTimeScaleDenominator,
+    /// The date is never reached because the time scale is 0
+    DateUnreachable,
+    /// The saved time scales don't start at the tick 0, are not ordered, don't keep the date continuous
+    /// or don't stop the time while paused
+    InvalidScaleSegments,
+    /// The `year_interval` of a recurrence rule is 0
+    ZeroYearInterval,
+    /// The interval of a repeating alarm is empty
+    ZeroAlarmInterval,
+    /// The real time duration of a tick given to a `TickDriver` is 0
+    ZeroTickDuration,
+    /// The maximum number of ticks per frame given to a `TickDriver` is 0
+    ZeroMaxStepsPerFrame,
+    /// The time can't go before the tick 0
+    TickUnderflow {
+        /// The tick before the update
+        current_tick: usize,
+        /// Number of ticks requested to be removed
+        rewind: usize,
+    },
+}
+
+impl fmt::Display for TickTimeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TickTimeError::ZeroSecondsPerTick => write!(f, "The minimum value for seconds_per_tick is 1"),
+            TickTimeError::ZeroTickRate => write!(f, "The minimum value for the milliseconds and ticks of tick_rate is 1"),
+            TickTimeError::MonthSeasonSumMismatch { months_total, seasons_total } => write!(
+                f, "The sum of months_durations ({}) and seasons_durations ({}) should be the same to keep consistent",
+                months_total, seasons_total
+            ),
+            TickTimeError::EmptyMonths => write!(f, "months_durations should contain at least one month"),
+            TickTimeError::ZeroMonthDuration { month } => write!(f, "The month {} should last at least one day", month),
+            TickTimeError::EmptySeasons => write!(f, "seasons_durations should contain at least one season"),
+            TickTimeError::ZeroSeasonDuration { season } => write!(f, "The season {} should last at least one day", season),
+            TickTimeError::SeasonsOffsetOutOfRange { seasons_offset, year_duration } => write!(
+                f, "The seasons offset {} should be lower than the year duration ({})", seasons_offset, year_duration
+            ),
+            TickTimeError::ZeroHoursInDay => write!(f, "The minimum value for hours_in_a_day is 1"),
+            TickTimeError::ZeroWeekDuration => write!(f, "The minimum value for week_duration is 1"),
+            TickTimeError::MonthNamesCountMismatch { expected, actual } => write!(
+                f, "{} month names are given but the calendar has {} months", actual, expected
+            ),
+            TickTimeError::SeasonNamesCountMismatch { expected, actual } => write!(
+                f, "{} season names are given but the calendar has {} seasons", actual, expected
+            ),
+            TickTimeError::WeekdayNamesCountMismatch { expected, actual } => write!(
+                f, "{} weekday names are given but a week lasts {} days", actual, expected
+            ),
+            TickTimeError::MonthOutOfRange { month, months_in_year } => write!(
+                f, "The month {} should be lower than the number of months in a year ({})", month, months_in_year
+            ),
+            TickTimeError::DayOutOfRange { day, days_in_month } => write!(
+                f, "The day {} should be lower than the number of days in its month ({})", day, days_in_month
+            ),
+            TickTimeError::WeekdayOutOfRange { weekday, week_duration } => write!(
+                f, "The weekday {} should be lower than the week duration ({})", weekday, week_duration
+            ),
+            TickTimeError::HourOutOfRange { hour, hours_in_a_day } => write!(
+                f, "The hour {} should be lower than the number of hours in a day ({})", hour, hours_in_a_day
+            ),
+            TickTimeError::MinuteOutOfRange { minute } => write!(f, "The minute {} should be lower than 60", minute),
+            TickTimeError::SecondOutOfRange { second } => write!(f, "The second {} should be lower than 60", second),
+            TickTimeError::DateBeforeEpoch => write!(f, "The date is before the epoch"),
+            TickTimeError::TickOverflow => write!(f, "The date or duration is too large to be represented by a tick"),
+            TickTimeError::ParseMismatch { position } => write!(f, "The input doesn't match the pattern at byte {}", position),
+            TickTimeError::InconsistentDate { position } => write!(
+                f, "The value at byte {} doesn't match the rest of the date", position
+            ),
+            TickTimeError::CalendarDependentDuration => write!(
+                f, "Months and years have no fixed number of ticks, use TickTime::add_duration or TickTime::ticks_in"
+            ),
+            TickTimeError::ZeroTimeScaleDenominator => write!(f, "The minimum value for the denominator of a time scale is 1"),
+            TickTimeError::DateUnreachable => write!(f, "The date is never reached while the time scale is 0"),
+            TickTimeError::InvalidScaleSegments => write!(
+                f, "The saved time scales should start at the tick 0, be ordered by tick, keep the date continuous and stop the time while paused"
+            ),
+            TickTimeError::ZeroYearInterval => write!(f, "The minimum value for year_interval is 1"),
+            TickTimeError::ZeroAlarmInterval => write!(f, "The interval of a repeating alarm should not be empty"),
+            TickTimeError::ZeroTickDuration => write!(f, "The real time duration of a tick should not be 0"),
+            TickTimeError::ZeroMaxStepsPerFrame => write!(f, "The minimum value for max_steps_per_frame is 1"),
+            TickTimeError::TickUnderflow { current_tick, rewind } => write!(
+                f, "Cannot rewind {} ticks from the tick {}, the time can't go before the tick 0", rewind, current_tick
+            ),
+        }
+    }
+}
+
+impl Error for TickTimeError {}
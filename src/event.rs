@@ -1,6 +1,14 @@
+use crate::alarm::FiredAlarm;
+use crate::date::GameDateTime;
+
 /// Contains all the updated values during this tick. Usefull to create an event oriented
 /// system.
+///
+/// The `*_update` fields cascade: when a unit changes, every smaller unit is marked as updated,
+/// even if its value stayed the same. `changed_units` only holds the units whose value changed,
+/// and `crossed` tells whether a boundary of a unit was passed.
 #[derive(Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickTimeEvent {
     /// Some(val) if computed second has been updated, None otherwise.
     /// Note : old value and new value can be the same if the tick is 60 seconds
@@ -14,6 +22,9 @@ pub struct TickTimeEvent {
     /// Some(val) if computed day has been updated, None otherwise.
     /// Note : old value and new value can be the same if the tick is n month or n year
     pub day_update: Option<TicketTimeEventValue>,
+    /// Some(val) if computed day of the week has been updated, None otherwise.
+    /// Note : old value and new value can be the same if the tick is n week(s)
+    pub weekday_update: Option<TicketTimeEventValue>,
     /// Some(val) if computed week has been updated, None otherwise.
     /// Note : old value and new value can be the same if the tick is n year
     pub week_update: Option<TicketTimeEventValue>,
@@ -25,10 +36,159 @@ pub struct TickTimeEvent {
     pub season_update: Option<TicketTimeEventValue>,
     /// Some(val) if  computed year has been updated, None otherwise.
     pub year_update: Option<TicketTimeEventValue>,
+    /// Whether the time moved forward or backward to produce this event
+    pub direction: TickDirection,
+    /// Alarms fired during the update, in chronological order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alarms: Vec<FiredAlarm>,
+    /// Date before the update
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub before: GameDateTime,
+    /// Date after the update
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub after: GameDateTime,
+    /// Tick before the update
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub from_tick: usize,
+    /// Tick after the update
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub to_tick: usize,
+    /// Units whose value is different after the update, from the second to the year
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub changed_units: Vec<TimeUnit>,
+    /// Units of which at least one boundary was passed, from the second to the year. A unit can
+    /// be crossed without changing, when a whole year goes by for the month for example.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub crossed_units: Vec<TimeUnit>,
+}
+
+impl TickTimeEvent {
+    /// Whether the value of the unit is different after the update
+    pub fn has_changed(&self, unit: TimeUnit) -> bool {
+        self.changed_units.contains(&unit)
+    }
+
+    /// Whether the update passed at least one boundary of the unit, like the start of a month
+    /// for `TimeUnit::Month`, even if the value of the unit is the same after the update
+    pub fn crossed(&self, unit: TimeUnit) -> bool {
+        self.crossed_units.contains(&unit)
+    }
+
+    /// Combine this event with the event that followed it, as if both updates happened at once.
+    /// Values keep the old value of the first update and the new value of the last one.
+    pub fn merge(self, next: TickTimeEvent) -> TickTimeEvent {
+        let crossed_units = TimeUnit::ALL
+            .iter()
+            .copied()
+            .filter(|unit| self.crossed(*unit) || next.crossed(*unit))
+            .collect();
+        let mut event = TickTimeEvent {
+            second_update: merge_values(self.second_update, next.second_update),
+            minute_update: merge_values(self.minute_update, next.minute_update),
+            hour_update: merge_values(self.hour_update, next.hour_update),
+            day_update: merge_values(self.day_update, next.day_update),
+            weekday_update: merge_values(self.weekday_update, next.weekday_update),
+            week_update: merge_values(self.week_update, next.week_update),
+            month_update: merge_values(self.month_update, next.month_update),
+            season_update: merge_values(self.season_update, next.season_update),
+            year_update: merge_values(self.year_update, next.year_update),
+            direction: next.direction,
+            alarms: self.alarms.into_iter().chain(next.alarms).collect(),
+            before: self.before,
+            after: next.after,
+            from_tick: self.from_tick,
+            to_tick: next.to_tick,
+            changed_units: vec![],
+            crossed_units,
+        };
+        event.changed_units = event.updated_values_units();
+        event
+    }
+
+    /// Units whose update has different old and new values
+    pub(crate) fn updated_values_units(&self) -> Vec<TimeUnit> {
+        TimeUnit::ALL
+            .iter()
+            .copied()
+            .filter(|unit| self.update(*unit).is_some_and(|update| update.old_value != update.new_value))
+            .collect()
+    }
+}
+
+fn merge_values(first: Option<TicketTimeEventValue>, next: Option<TicketTimeEventValue>) -> Option<TicketTimeEventValue> {
+    match (first, next) {
+        (Some(first), Some(next)) => Some(TicketTimeEventValue { old_value: first.old_value, new_value: next.new_value }),
+        (first, next) => first.or(next),
+    }
+}
+
+/// A unit of the calendar, used to select the updates of an event and the boundaries it crossed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeUnit {
+    /// Crossed at the start of each second
+    Second,
+    /// Crossed at the start of each minute
+    Minute,
+    /// Crossed at the start of each hour
+    Hour,
+    /// Crossed at the start of each day, which also changes the day of the week
+    Day,
+    /// Crossed at the start of each week. Weeks are counted from the start of each year, so the
+    /// start of a year also starts a week.
+    Week,
+    /// Crossed at the start of each month
+    Month,
+    /// Crossed at the start of each season, which is not the start of a year when the seasons are offset
+    Season,
+    /// Crossed at the start of each year
+    Year,
+}
+
+impl TimeUnit {
+    /// Every unit, from the smallest to the largest
+    pub const ALL: [TimeUnit; 8] = [
+        TimeUnit::Second,
+        TimeUnit::Minute,
+        TimeUnit::Hour,
+        TimeUnit::Day,
+        TimeUnit::Week,
+        TimeUnit::Month,
+        TimeUnit::Season,
+        TimeUnit::Year,
+    ];
+}
+
+impl TickTimeEvent {
+    /// Return the update of the given unit, None if the unit has not been updated
+    pub fn update(&self, unit: TimeUnit) -> Option<&TicketTimeEventValue> {
+        match unit {
+            TimeUnit::Second => self.second_update.as_ref(),
+            TimeUnit::Minute => self.minute_update.as_ref(),
+            TimeUnit::Hour => self.hour_update.as_ref(),
+            TimeUnit::Day => self.day_update.as_ref(),
+            TimeUnit::Week => self.week_update.as_ref(),
+            TimeUnit::Month => self.month_update.as_ref(),
+            TimeUnit::Season => self.season_update.as_ref(),
+            TimeUnit::Year => self.year_update.as_ref(),
+        }
+    }
+}
+
+/// The direction in which the time moved during an event
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TickDirection {
+    /// The current tick increased, or stayed the same
+    #[default]
+    Forward,
+    /// The current tick decreased, for example with `TickTime::rewind`
+    Backward,
 }
 
 /// Represents the computed values before and after the current triggered event
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TicketTimeEventValue {
     /// The value before the tick is updated
     pub old_value: usize,
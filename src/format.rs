@@ -0,0 +1,324 @@
+use crate::error::TickTimeError;
+use crate::date::GameDateTime;
+use crate::{
+    compute_values, get_hours_in_a_day, get_total_seconds, get_unit_names, tick_for_date, verify_options, TickTime,
+    TickTimeOptions, EARTHLIKE_MONTH_NAMES, EARTHLIKE_SEASON_NAMES, EARTHLIKE_WEEKDAY_NAMES,
+};
+
+/// A value that can be written by `TickTime::format`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FormatField {
+    /// `%Y`
+    Year,
+    /// `%m`
+    Month,
+    /// `%B`
+    MonthName,
+    /// `%d`
+    Day,
+    /// `%W`
+    Week,
+    /// `%u`
+    Weekday,
+    /// `%A`
+    WeekdayName,
+    /// `%Q`
+    Season,
+    /// `%q`
+    SeasonName,
+    /// `%H`
+    Hour,
+    /// `%I`
+    HalfDayHour,
+    /// `%p`
+    HalfDay,
+    /// `%M`
+    Minute,
+    /// `%S`
+    Second,
+}
+
+/// How a numeric value is padded to its default width
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Padding {
+    /// Default, `%Y`
+    Zero,
+    /// `%_Y`
+    Space,
+    /// `%-Y`
+    None,
+}
+
+/// A piece of a format pattern
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum FormatItem {
+    Literal(String),
+    Field(FormatField, Padding),
+}
+
+impl FormatField {
+    fn from_token(token: char) -> Option<Self> {
+        match token {
+            'Y' => Some(FormatField::Year),
+            'm' => Some(FormatField::Month),
+            'B' => Some(FormatField::MonthName),
+            'd' => Some(FormatField::Day),
+            'W' => Some(FormatField::Week),
+            'u' => Some(FormatField::Weekday),
+            'A' => Some(FormatField::WeekdayName),
+            'Q' => Some(FormatField::Season),
+            'q' => Some(FormatField::SeasonName),
+            'H' => Some(FormatField::Hour),
+            'I' => Some(FormatField::HalfDayHour),
+            'p' => Some(FormatField::HalfDay),
+            'M' => Some(FormatField::Minute),
+            'S' => Some(FormatField::Second),
+            _ => None,
+        }
+    }
+
+    /// Width of the value when padded
+    pub(crate) fn width(self) -> usize {
+        match self {
+            FormatField::Year => 4,
+            FormatField::Weekday | FormatField::WeekdayName | FormatField::Season | FormatField::SeasonName => 1,
+            _ => 2,
+        }
+    }
+}
+
+/// Split a pattern into literals and fields. Unknown tokens are kept as literals.
+pub(crate) fn parse_pattern(pattern: &str) -> Vec<FormatItem> {
+    let mut items = vec![];
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+        let padding = match chars.peek() {
+            Some('-') => Padding::None,
+            Some('_') => Padding::Space,
+            _ => Padding::Zero,
+        };
+        if padding != Padding::Zero {
+            chars.next();
+        }
+        match chars.next() {
+            Some('%') if padding == Padding::Zero => literal.push('%'),
+            Some(token) => match FormatField::from_token(token) {
+                Some(field) => {
+                    if !literal.is_empty() {
+                        items.push(FormatItem::Literal(std::mem::take(&mut literal)));
+                    }
+                    items.push(FormatItem::Field(field, padding));
+                }
+                None => {
+                    literal.push('%');
+                    match padding {
+                        Padding::None => literal.push('-'),
+                        Padding::Space => literal.push('_'),
+                        Padding::Zero => {}
+                    }
+                    literal.push(token);
+                }
+            },
+            None => literal.push('%'),
+        }
+    }
+    if !literal.is_empty() {
+        items.push(FormatItem::Literal(literal));
+    }
+    items
+}
+
+fn pad(value: usize, width: usize, padding: Padding) -> String {
+    match padding {
+        Padding::Zero => format!("{:0width$}", value, width = width),
+        Padding::Space => format!("{:width$}", value, width = width),
+        Padding::None => value.to_string(),
+    }
+}
+
+impl TickTime {
+    /// Format the computed values following a strftime-like `pattern`.
+    ///
+    /// Numeric values are zero based, like the values returned by the accessors, and zero padded
+    /// by default. Use `%-X` to disable the padding or `%_X` to pad with spaces. Names fall back to
+    /// the numeric value when the calendar doesn't name the unit.
+    ///
+    /// | Token | Value |
+    /// |-------|-------|
+    /// | `%Y` | Year |
+    /// | `%m` | Month |
+    /// | `%B` | Month name |
+    /// | `%d` | Day of the month |
+    /// | `%W` | Week of the year |
+    /// | `%u` | Day of the week |
+    /// | `%A` | Day of the week name |
+    /// | `%Q` | Season |
+    /// | `%q` | Season name |
+    /// | `%H` | Hour of the day |
+    /// | `%I` | Hour of the half day, from 1 to 12 for a 24 hours day |
+    /// | `%p` | `AM` or `PM` |
+    /// | `%M` | Minute |
+    /// | `%S` | Second |
+    /// | `%%` | A literal `%` |
+    pub fn format(&self, pattern: &str) -> String {
+        let half_day = (get_hours_in_a_day(&self.options.tick_time_type) / 2).max(1);
+        parse_pattern(pattern)
+            .into_iter()
+            .map(|item| match item {
+                FormatItem::Literal(literal) => literal,
+                FormatItem::Field(field, padding) => {
+                    let number = |value: usize| pad(value, field.width(), padding);
+                    match field {
+                        FormatField::Year => number(self.year()),
+                        FormatField::Month => number(self.month()),
+                        FormatField::MonthName => self.month_name().map_or_else(|| number(self.month()), str::to_string),
+                        FormatField::Day => number(self.day()),
+                        FormatField::Week => number(self.week()),
+                        FormatField::Weekday => number(self.weekday()),
+                        FormatField::WeekdayName => self.weekday_name().map_or_else(|| number(self.weekday()), str::to_string),
+                        FormatField::Season => number(self.season()),
+                        FormatField::SeasonName => self.season_name().map_or_else(|| number(self.season()), str::to_string),
+                        FormatField::Hour => number(self.hour()),
+                        FormatField::HalfDayHour => number(match self.hour() % half_day {
+                            0 => half_day,
+                            hour => hour,
+                        }),
+                        FormatField::HalfDay => if self.hour() < half_day { "AM" } else { "PM" }.to_string(),
+                        FormatField::Minute => number(self.minute()),
+                        FormatField::Second => number(self.second()),
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Values read by `TickTime::parse`. Missing values are 0.
+#[derive(Default)]
+struct ParsedDate {
+    date: GameDateTime,
+    /// Hour read with `%I`
+    half_day_hour: Option<usize>,
+    /// Whether `%p` read `PM`
+    post_meridiem: bool,
+    /// Week, weekday and season values read, with their position in the input
+    computed_values: Vec<(FormatField, usize, usize)>,
+}
+
+/// Read a number at the beginning of `input`, returns it with the number of bytes read
+fn read_number(input: &str, padding: Padding, width: Option<usize>) -> Option<(usize, usize)> {
+    let spaces = if padding == Padding::Space { input.len() - input.trim_start_matches(' ').len() } else { 0 };
+    let digits = input[spaces..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .take(width.unwrap_or(usize::MAX))
+        .count();
+    if digits == 0 {
+        return None;
+    }
+    input[spaces..spaces + digits].parse().ok().map(|value| (value, spaces + digits))
+}
+
+/// Names that can be read for a field, None when the field is read as a number
+fn get_field_names(options: &TickTimeOptions, field: FormatField) -> Option<Vec<&str>> {
+    match field {
+        FormatField::MonthName => get_unit_names(options, |names| &names.months, &EARTHLIKE_MONTH_NAMES),
+        FormatField::WeekdayName => get_unit_names(options, |names| &names.weekdays, &EARTHLIKE_WEEKDAY_NAMES),
+        FormatField::SeasonName => get_unit_names(options, |names| &names.seasons, &EARTHLIKE_SEASON_NAMES),
+        FormatField::HalfDay => Some(vec!["AM", "PM"]),
+        _ => None,
+    }
+}
+
+/// Read the longest name at the beginning of `input`, returns its index with the number of bytes read
+fn read_name(input: &str, names: &[&str]) -> Option<(usize, usize)> {
+    names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| !name.is_empty() && input.starts_with(*name))
+        .max_by_key(|(_, name)| name.len())
+        .map(|(index, name)| (index, name.len()))
+}
+
+impl TickTime {
+    /// Parse a date written with a `TickTime::format` pattern and return the earliest tick at or
+    /// after this date for the given options. Values missing from the pattern are 0, and the
+    /// week, weekday and season values, when present, must match the parsed date. Dates too far to
+    /// be represented by a tick return `TickTimeError::TickOverflow`.
+    pub fn parse(input: &str, pattern: &str, options: &TickTimeOptions) -> Result<usize, TickTimeError> {
+        verify_options(options)?;
+        let tick_time_type = &options.tick_time_type;
+        let items = parse_pattern(pattern);
+        let mut parsed = ParsedDate::default();
+        let mut position = 0;
+
+        for (index, item) in items.iter().enumerate() {
+            let rest = &input[position..];
+            match item {
+                FormatItem::Literal(literal) => {
+                    if !rest.starts_with(literal.as_str()) {
+                        return Err(TickTimeError::ParseMismatch { position });
+                    }
+                    position += literal.len();
+                }
+                FormatItem::Field(field, padding) => {
+                    // Without separator, the value can only be delimited by its padded width
+                    let width = match items.get(index + 1) {
+                        Some(FormatItem::Field(..)) if *padding == Padding::Zero => Some(field.width()),
+                        _ => None,
+                    };
+                    let read = match get_field_names(options, *field) {
+                        Some(names) => read_name(rest, &names),
+                        None => read_number(rest, *padding, width),
+                    };
+                    let (value, length) = read.ok_or(TickTimeError::ParseMismatch { position })?;
+                    match field {
+                        FormatField::Year => parsed.date.year = value,
+                        FormatField::Month | FormatField::MonthName => parsed.date.month = value,
+                        FormatField::Day => parsed.date.day = value,
+                        FormatField::Hour => parsed.date.hour = value,
+                        FormatField::HalfDayHour => parsed.half_day_hour = Some(value),
+                        FormatField::HalfDay => parsed.post_meridiem = value == 1,
+                        FormatField::Minute => parsed.date.minute = value,
+                        FormatField::Second => parsed.date.second = value,
+                        FormatField::Week | FormatField::Weekday | FormatField::WeekdayName
+                        | FormatField::Season | FormatField::SeasonName => parsed.computed_values.push((*field, value, position)),
+                    }
+                    position += length;
+                }
+            }
+        }
+        if position != input.len() {
+            return Err(TickTimeError::ParseMismatch { position });
+        }
+
+        if let Some(hour) = parsed.half_day_hour {
+            let hours_in_a_day = get_hours_in_a_day(tick_time_type);
+            let half_day = (hours_in_a_day / 2).max(1);
+            if hour == 0 || hour > half_day {
+                return Err(TickTimeError::HourOutOfRange { hour, hours_in_a_day });
+            }
+            parsed.date.hour = hour % half_day + if parsed.post_meridiem { half_day } else { 0 };
+        }
+        let tick = tick_for_date(options, &parsed.date)?;
+
+        let values = compute_values(options, get_total_seconds(tick_time_type, &parsed.date)?);
+        for (field, value, position) in parsed.computed_values {
+            let expected = match field {
+                FormatField::Week => values.week,
+                FormatField::Weekday | FormatField::WeekdayName => values.weekday,
+                _ => values.season,
+            };
+            if value != expected {
+                return Err(TickTimeError::InconsistentDate { position });
+            }
+        }
+
+        Ok(tick)
+    }
+}
@@ -1,19 +1,44 @@
+use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::Formatter;
-use crate::event::{TickTimeEvent, TicketTimeEventValue};
+use crate::alarm::AlarmScheduler;
+use crate::date::GameDateTime;
+use crate::error::TickTimeError;
+use crate::event::{TickDirection, TickTimeEvent, TicketTimeEventValue};
+use crate::listener::ListenerRegistry;
 
+pub mod alarm;
+mod boundary;
+pub mod date;
+pub mod driver;
+pub mod duration;
+pub mod error;
 pub mod event;
+mod format;
+pub mod listener;
+pub mod recurrence;
 mod lib_tests;
 
 const LUNAR_MONTH_DURATION: usize = 30;
 const LUNAR_YEAR_DURATION: usize = LUNAR_MONTH_DURATION * 12;
+/// Number of days in a full cycle of the gregorian calendar (400 years)
+const GREGORIAN_CYCLE_DURATION: usize = 146_097;
+
+const EARTHLIKE_MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+const EARTHLIKE_SEASON_NAMES: [&str; 4] = ["Winter", "Spring", "Summer", "Autumn"];
+const EARTHLIKE_WEEKDAY_NAMES: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
 
 /// The way the in game datetime will be handled
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TickTimeType {
     /// The date and time is like on the planet earth (12 months, 24 hours a day, 60 minutes an hour, 60 seconds a minute)
     EarthLike {
-        /// How much seconds represent a tick. Should be minimum 1.
+        /// How much seconds represent a tick. Should be minimum 1. Ignored when
+        /// `TickTimeOptions::tick_rate` is set.
         seconds_per_tick: usize,
         /// Which kind of calendar to compute values
         month_type: EarthLikeMonthType,
@@ -21,21 +46,36 @@ pub enum TickTimeType {
     /// A configurable date and time type. An hour will still be 60 minutes and a minute 60 seconds.
     /// Note that sum of `season_duration` and `months_durations` must match to be consistent.
     Custom {
-        /// How much seconds represent a tick. Should be minimum 1.
+        /// How much seconds represent a tick. Should be minimum 1. Ignored when
+        /// `TickTimeOptions::tick_rate` is set.
         seconds_per_tick: usize,
         /// The duration of a day
         hours_in_a_day: usize,
         /// A list of month durations.
         months_durations: Vec<usize>,
-        /// A list of seasons durations.
+        /// A list of seasons durations. There can be any number of seasons.
         seasons_durations: Vec<usize>,
         /// duration of a single week.
         week_duration: usize,
     },
 }
 
+/// Names given to the units of a calendar. Each list must either be empty, to keep the default
+/// names of the calendar, or contain exactly one name per month, season or day of the week.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalendarNames {
+    /// Names of the months, in the order of the year
+    pub months: Vec<String>,
+    /// Names of the seasons, the first one being the season starting the year
+    pub seasons: Vec<String>,
+    /// Names of the days of the week, the first one being the weekday 0
+    pub weekdays: Vec<String>,
+}
+
 /// List of available month type for an Earth-like calendar
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EarthLikeMonthType {
     /// A simple mode where each month is 30 days long
     Lunar,
@@ -43,16 +83,143 @@ pub enum EarthLikeMonthType {
     Real,
 }
 
-/// Options to give to `TickTime` to enable/configure features
+/// Options to give to `TickTime` to enable/configure features.
+/// Only `tick_time_type` and `compute_events` are required, the other options can be left to
+/// their default value with `..Default::default()`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TickTimeOptions {
     /// Type of time to use when computing values to display
     pub tick_time_type: TickTimeType,
     /// Flag to decide whether or not the tick() function compute and returns update events
     pub compute_events: bool,
+    /// The in game date and time represented by the tick 0
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub epoch: TickTimeEpoch,
+    /// Day of the year on which the first season of a `Custom` calendar starts. The days before
+    /// it belong to the last season, which allows a season to span the new year. Should be lower
+    /// than the year duration. Ignored by the EarthLike calendars.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub seasons_offset: usize,
+    /// Names of the months, seasons and weekdays. When None, EarthLike calendars use English
+    /// names and `Custom` calendars leave their values unnamed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub names: Option<CalendarNames>,
+    /// Fractional duration of a tick, replacing the `seconds_per_tick` of the `tick_time_type` when set.
+    /// None by default.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tick_rate: Option<TickRate>,
+}
+
+impl Default for TickTimeOptions {
+    /// An EarthLike real calendar starting at the year 0, one second per tick, without events
+    fn default() -> Self {
+        TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Real },
+            compute_events: false,
+            epoch: Default::default(),
+            seasons_offset: 0,
+            names: None,
+            tick_rate: None,
+        }
+    }
+}
+
+/// In game time represented by a number of ticks, for ticks lasting less than a second or a
+/// non integer number of seconds. `ticks` ticks last `milliseconds` milliseconds of in game time.
+/// Both values should be minimum 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickRate {
+    /// In game milliseconds lasted by `ticks` ticks
+    pub milliseconds: usize,
+    /// Number of ticks lasting `milliseconds` milliseconds
+    pub ticks: usize,
 }
 
+impl TickRate {
+    /// Each tick lasts `milliseconds` milliseconds
+    pub fn milliseconds_per_tick(milliseconds: usize) -> Self {
+        TickRate { milliseconds, ticks: 1 }
+    }
+
+    /// `ticks` ticks last `seconds` seconds, e.g. `TickRate::seconds_per_ticks(1, 10)` for ten ticks per second
+    pub fn seconds_per_ticks(seconds: usize, ticks: usize) -> Self {
+        TickRate { milliseconds: seconds * 1000, ticks }
+    }
+}
+
+/// An in game date and time used as the starting point of a `TickTime`.
+/// Each value is zero based and must be valid for the configured `TickTimeType`.
+/// The default epoch is the year 0, month 0, day 0 at 00:00:00.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickTimeEpoch {
+    /// Year of the tick 0
+    pub year: usize,
+    /// Month of the tick 0, in the year
+    pub month: usize,
+    /// Day of the tick 0, in the month
+    pub day: usize,
+    /// Hour of the tick 0
+    pub hour: usize,
+    /// Minute of the tick 0
+    pub minute: usize,
+    /// Second of the tick 0
+    pub second: usize,
+    /// Day of the week of the tick 0, lower than the week duration of the calendar
+    pub weekday: usize,
+}
+
+/// Speed of the in game time relative to the tick rate, as the ratio `numerator / denominator`.
+/// The default scale is 1, a scale of 0 stops the in game time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeScale {
+    /// Numerator of the scale
+    pub numerator: usize,
+    /// Denominator of the scale. Should be minimum 1.
+    pub denominator: usize,
+}
+
+impl TimeScale {
+    /// The in game time goes `times` times faster than the tick rate
+    pub fn times(times: usize) -> Self {
+        TimeScale { numerator: times, denominator: 1 }
+    }
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        TimeScale::times(1)
+    }
+}
+
+/// A time scale applied from `start_tick` until the start of the next segment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ScaleSegment {
+    /// First tick using the scale
+    start_tick: usize,
+    /// In game milliseconds elapsed since the epoch at `start_tick`
+    start_milliseconds: u128,
+    /// Scale of the segment
+    scale: TimeScale,
+}
+
+impl ScaleSegment {
+    /// In game milliseconds elapsed since the epoch at a tick after `start_tick`, following the scale.
+    /// Returns None when they are too many to be counted.
+    fn elapsed_milliseconds_at(&self, rate: TickRate, tick: usize) -> Option<u128> {
+        let milliseconds = ((tick - self.start_tick) as u128)
+            .checked_mul(rate.milliseconds as u128)?
+            .checked_mul(self.scale.numerator as u128)?
+            / (rate.ticks as u128 * self.scale.denominator as u128);
+        self.start_milliseconds.checked_add(milliseconds)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 struct TickTimeValue {
     /// Computed year, according to the tick_time_type
     year: usize,
@@ -62,6 +229,8 @@ struct TickTimeValue {
     month: usize,
     /// Computed week, according to the tick_time_type
     week: usize,
+    /// Computed day of the week, according to the tick_time_type and the epoch weekday
+    weekday: usize,
     /// Computed day, according to the tick_time_type
     day: usize,
     /// Computed hour, according to the tick_time_type
@@ -70,12 +239,35 @@ struct TickTimeValue {
     minute: usize,
     /// Computed second, according to the tick_time_type
     second: usize,
+    /// Computed millisecond, always 0 unless the tick rate has a sub second precision
+    millisecond: usize,
+}
+
+impl TickTimeValue {
+    fn date(&self) -> GameDateTime {
+        GameDateTime {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+            hour: self.hour,
+            minute: self.minute,
+            second: self.second,
+            season: self.season,
+            week: self.week,
+            weekday: self.weekday,
+        }
+    }
 }
 
 /// A `TickTime` helps to keep track of the current tick in the game.
 /// Following a `TickTimeType`, it will translate the current tick to
 /// a list of computed values, representing year, season, month...
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TickTimeState", into = "TickTimeState")
+)]
 pub struct TickTime {
     /// Options to configure / enable / disable features from the computing step
     options: TickTimeOptions,
@@ -85,37 +277,141 @@ pub struct TickTime {
     values: TickTimeValue,
     /// Last tick Computed values from the tick method
     old_values: TickTimeValue,
+    /// Time scales applied since the tick 0, ordered by start tick. The first one starts at the tick 0.
+    scale_segments: Vec<ScaleSegment>,
+    /// Time scale to restore on resume, set while the in game time is paused
+    paused_scale: Option<TimeScale>,
+    /// Listeners called on updates, not kept by clones
+    listeners: ListenerRegistry,
+    /// Alarms waiting to fire
+    alarms: AlarmScheduler,
 }
 
 impl TickTime {
     /// Initialise a TickTime with a given tick (usefull to reload the state of a save) and
-    /// a `TickTimeType`.
-    pub fn init(current_tick: usize, options: TickTimeOptions) -> Result<Self, &'static str> {
-        if let Err(e) = verify_tick_time_type_values(&options.tick_time_type) {
-            return Err(e);
-        }
+    /// a `TickTimeType`. Returns an error when the date of the tick is too far to be counted.
+    pub fn init(current_tick: usize, options: TickTimeOptions) -> Result<Self, TickTimeError> {
+        verify_options(&options)?;
         let mut tick_time = TickTime {
             current_tick,
             options,
             values: Default::default(),
-            old_values: Default::default()
+            old_values: Default::default(),
+            scale_segments: vec![ScaleSegment { start_tick: 0, start_milliseconds: 0, scale: Default::default() }],
+            paused_scale: None,
+            listeners: Default::default(),
+            alarms: Default::default(),
         };
-        tick_time.apply_current_tick();
+        tick_time.apply_current_tick()?;
         Ok(tick_time)
     }
 
-    /// Add a tick to the current_tick. Will also compute values
+    /// Add a tick to the current_tick. Will also compute values. Panics when the date of the next
+    /// tick is too far to be counted, `advance` returns an error instead.
     pub fn tick(&mut self) -> Option<TickTimeEvent> {
-        self.current_tick += 1;
-        self.apply_current_tick();
-        if self.options.compute_events {
-            Some(self.compute_event())
+        self.advance(1).expect("The date of the next tick can be counted")
+    }
+
+    /// Add `n` ticks to the current_tick at once. Values are computed only once, and the
+    /// returned event describes every value updated between the old and the new date.
+    /// Returns an error, without moving, when the date of the new tick is too far to be counted.
+    pub fn advance(&mut self, n: usize) -> Result<Option<TickTimeEvent>, TickTimeError> {
+        let tick = self.current_tick.checked_add(n).ok_or(TickTimeError::TickOverflow)?;
+        self.set_tick(tick)
+    }
+
+    /// Remove `n` ticks from the current_tick. Returns an error instead of going before the tick 0.
+    /// The returned event has a `TickDirection::Backward` direction.
+    pub fn rewind(&mut self, n: usize) -> Result<Option<TickTimeEvent>, TickTimeError> {
+        match self.current_tick.checked_sub(n) {
+            Some(tick) => self.set_tick(tick),
+            None => Err(TickTimeError::TickUnderflow { current_tick: self.current_tick, rewind: n }),
+        }
+    }
+
+    /// Move the current_tick to the given tick, forward or backward. Will also compute values.
+    /// Moving backward drops the time scales set after the new tick, the time scale applied to
+    /// the new tick is kept. Returns an error, without moving, when the date of the tick is too far to be counted.
+    pub fn set_tick(&mut self, tick: usize) -> Result<Option<TickTimeEvent>, TickTimeError> {
+        // Nothing changes when the date of the tick can't be counted
+        self.tick_total_seconds(tick)?;
+        let direction = if tick < self.current_tick { TickDirection::Backward } else { TickDirection::Forward };
+        let (from_tick, moved_forward) = (self.current_tick, tick > self.current_tick);
+        self.current_tick = tick;
+        if tick < from_tick {
+            self.scale_segments.retain(|segment| segment.start_tick <= tick);
+            // Going back before the pause would use the scale of the running time, the pause starts again here
+            if self.is_paused() {
+                self.push_scale_segment(TimeScale::times(0));
+            }
+        }
+        self.apply_current_tick()?;
+        // Firing the last alarm stops the tracking, the event is still needed to return it
+        let tracks_events = self.tracks_events();
+        let alarms = if moved_forward { self.fire_alarms(tick) } else { vec![] };
+        if !tracks_events || (self.is_paused() && self.values == self.old_values && alarms.is_empty()) {
+            return Ok(None);
+        }
+        let mut event = self.compute_event(from_tick);
+        event.direction = direction;
+        event.alarms = alarms;
+        self.listeners.notify(&event);
+        if self.options.compute_events || !event.alarms.is_empty() {
+            Ok(Some(event))
         }else{
-            None
+            Ok(None)
         }
     }
 
-    /// Return a tuple of computed usizes for (year, season, month, day, hour, minute, second)
+    /// Change the speed of the in game time from the current tick. The in game time elapsed
+    /// before the current tick is kept, so the date stays continuous. Scales set after the
+    /// current tick, before a rewind, are dropped. While paused, the scale is applied on resume.
+    pub fn set_time_scale(&mut self, scale: TimeScale) -> Result<(), TickTimeError> {
+        if scale.denominator == 0 {
+            return Err(TickTimeError::ZeroTimeScaleDenominator);
+        }
+        match self.paused_scale.as_mut() {
+            Some(paused_scale) => *paused_scale = scale,
+            None => self.push_scale_segment(scale),
+        }
+        Ok(())
+    }
+
+    /// Return the time scale applied to the current tick, or the one applied on resume while paused
+    pub fn time_scale(&self) -> TimeScale {
+        self.paused_scale.unwrap_or(self.scale_segment(self.current_tick).scale)
+    }
+
+    /// Stop the in game time from the current tick. Ticks keep being counted by `current_tick`
+    /// but the date doesn't change and no event is returned until `resume` is called. Rewinding
+    /// while paused moves the date back, where it stays stopped.
+    pub fn pause(&mut self) {
+        if self.paused_scale.is_none() {
+            self.paused_scale = Some(self.time_scale());
+            self.push_scale_segment(TimeScale::times(0));
+        }
+    }
+
+    /// Restart the in game time from the current tick, with the time scale used before the pause
+    pub fn resume(&mut self) {
+        if let Some(scale) = self.paused_scale.take() {
+            self.push_scale_segment(scale);
+        }
+    }
+
+    /// Whether the in game time is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused_scale.is_some()
+    }
+
+    /// Number of in game milliseconds elapsed since the epoch. Unlike `current_tick`, it doesn't
+    /// grow while the time is paused.
+    pub fn elapsed_milliseconds(&self) -> u128 {
+        self.elapsed_milliseconds_at(self.current_tick).expect("The date of the current tick is counted")
+    }
+
+    /// Return a tuple of computed usizes for (year, season, week, month, day, hour, minute, second).
+    /// `TickTime::now` returns the same date with named fields.
     pub fn values(&self) -> (usize, usize, usize, usize, usize, usize, usize, usize) {
         (
             self.values.year,
@@ -129,8 +425,14 @@ impl TickTime {
         )
     }
 
-    fn compute_event(&self) -> TickTimeEvent {
-        let mut event = TickTimeEvent::default();
+    fn compute_event(&self, from_tick: usize) -> TickTimeEvent {
+        let mut event = TickTimeEvent {
+            before: self.old_values.date(),
+            after: self.values.date(),
+            from_tick,
+            to_tick: self.current_tick,
+            ..Default::default()
+        };
         let mut update_level = 0;
 
         if self.old_values.year != self.values.year {
@@ -156,6 +458,10 @@ impl TickTime {
             event.day_update = Some(TicketTimeEventValue{ old_value: self.old_values.day, new_value: self.values.day });
         }
 
+        if update_level > 0 || self.old_values.weekday != self.values.weekday {
+            event.weekday_update = Some(TicketTimeEventValue{ old_value: self.old_values.weekday, new_value: self.values.weekday });
+        }
+
         if update_level > 0 || self.old_values.hour != self.values.hour {
             update_level += 1;
             event.hour_update = Some(TicketTimeEventValue{ old_value: self.old_values.hour, new_value: self.values.hour });
@@ -170,6 +476,9 @@ impl TickTime {
             event.second_update = Some(TicketTimeEventValue{ old_value: self.old_values.second, new_value: self.values.second });
         }
 
+        event.changed_units = event.updated_values_units();
+        let (first, last) = (event.before.min(event.after), event.before.max(event.after));
+        event.crossed_units = self.crossed_units(&first, &last);
         event
     }
 
@@ -178,6 +487,42 @@ impl TickTime {
         self.current_tick
     }
 
+    /// Return the computed date and time
+    pub fn now(&self) -> GameDateTime {
+        self.values.date()
+    }
+
+    /// Return the earliest tick at or after the given date. Returns an error when the date doesn't
+    /// exist in the calendar, is before the epoch or is too far to be represented by a tick.
+    /// The time scales are followed, so a date after the current one can't be reached while the time scale is 0.
+    pub fn tick_for(&self, date: &GameDateTime) -> Result<usize, TickTimeError> {
+        let milliseconds = date_elapsed_milliseconds(&self.options, date)?;
+        let rate = get_tick_rate(&self.options);
+        let mut segments = self.scale_segments.iter().peekable();
+        while let Some(segment) = segments.next() {
+            if let Some(next) = segments.peek() {
+                if next.start_milliseconds < milliseconds {
+                    continue;
+                }
+            }
+            let remaining = milliseconds.saturating_sub(segment.start_milliseconds);
+            if remaining == 0 {
+                return Ok(segment.start_tick);
+            }
+            if segment.scale.numerator == 0 {
+                break;
+            }
+            let divisor = rate.milliseconds as u128 * segment.scale.numerator as u128;
+            return remaining
+                .checked_mul(rate.ticks as u128 * segment.scale.denominator as u128)
+                .map(|dividend| dividend / divisor + u128::from(dividend % divisor != 0))
+                .and_then(|ticks| usize::try_from(ticks).ok())
+                .and_then(|ticks| segment.start_tick.checked_add(ticks))
+                .ok_or(TickTimeError::TickOverflow);
+        }
+        Err(TickTimeError::DateUnreachable)
+    }
+
     /// Return the read only computed year
     pub fn year(&self) -> usize {
         self.values.year
@@ -203,6 +548,11 @@ impl TickTime {
         self.values.day
     }
 
+    /// Return the read only computed day of the week
+    pub fn weekday(&self) -> usize {
+        self.values.weekday
+    }
+
     /// Return the read only computed hour
     pub fn hour(&self) -> usize {
         self.values.hour
@@ -218,80 +568,233 @@ impl TickTime {
         self.values.second
     }
 
-    fn apply_current_tick(&mut self) {
-        if self.options.compute_events {
+    /// Return the read only computed millisecond
+    pub fn millisecond(&self) -> usize {
+        self.values.millisecond
+    }
+
+    /// Return the name of the computed month, if the calendar names its months
+    pub fn month_name(&self) -> Option<&str> {
+        get_unit_name(&self.options, |names| &names.months, &EARTHLIKE_MONTH_NAMES, self.values.month)
+    }
+
+    /// Return the name of the computed season, if the calendar names its seasons
+    pub fn season_name(&self) -> Option<&str> {
+        get_unit_name(&self.options, |names| &names.seasons, &EARTHLIKE_SEASON_NAMES, self.values.season)
+    }
+
+    /// Return the name of the computed day of the week, if the calendar names its weekdays
+    pub fn weekday_name(&self) -> Option<&str> {
+        get_unit_name(&self.options, |names| &names.weekdays, &EARTHLIKE_WEEKDAY_NAMES, self.values.weekday)
+    }
+
+    /// Whether events are computed, to be returned, for the listeners or for the alarms
+    fn tracks_events(&self) -> bool {
+        self.options.compute_events || !self.listeners.is_empty() || !self.alarms.is_empty()
+    }
+
+    fn apply_current_tick(&mut self) -> Result<(), TickTimeError> {
+        let total_seconds = self.tick_total_seconds(self.current_tick)?;
+        if self.tracks_events() {
             self.old_values = self.values.clone();
         }
-        match self.options.tick_time_type {
-            TickTimeType::EarthLike { .. } => { self.compute_earthlike_time(); }
-            TickTimeType::Custom { .. } => { self.compute_custom_date_time_values() }
+        self.values = compute_values(&self.options, total_seconds);
+        self.values.millisecond = (self.tick_total_milliseconds(self.current_tick)? % 1000) as usize;
+        Ok(())
+    }
+
+    /// Time scale segment applied to the given tick
+    fn scale_segment(&self, tick: usize) -> &ScaleSegment {
+        self.scale_segments.iter().rev().find(|segment| segment.start_tick <= tick).expect("The first segment starts at the tick 0")
+    }
+
+    /// Apply a time scale from the current tick, dropping the scales set after it. The segments
+    /// starting at the current tick are replaced and the previous one continues when its scale is the same.
+    fn push_scale_segment(&mut self, scale: TimeScale) {
+        let (start_tick, start_milliseconds) = (self.current_tick, self.elapsed_milliseconds());
+        self.scale_segments.retain(|segment| segment.start_tick < start_tick);
+        if self.scale_segments.last().is_some_and(|segment| segment.scale == scale) {
+            return;
         }
+        self.scale_segments.push(ScaleSegment { start_tick, start_milliseconds, scale });
+    }
+
+    /// Number of in game milliseconds between the epoch and the given tick, following the time scales
+    fn elapsed_milliseconds_at(&self, tick: usize) -> Option<u128> {
+        self.scale_segment(tick).elapsed_milliseconds_at(get_tick_rate(&self.options), tick)
+    }
+
+    /// Number of milliseconds between the beginning of the calendar and the given tick
+    fn tick_total_milliseconds(&self, tick: usize) -> Result<u128, TickTimeError> {
+        self.elapsed_milliseconds_at(tick)
+            .and_then(|milliseconds| milliseconds.checked_add(epoch_total_seconds(&self.options) as u128 * 1000))
+            .ok_or(TickTimeError::TickOverflow)
+    }
+
+    /// Number of seconds between the beginning of the calendar and the given tick. Returns an
+    /// error when they are too many to be counted by the calendar.
+    fn tick_total_seconds(&self, tick: usize) -> Result<usize, TickTimeError> {
+        usize::try_from(self.tick_total_milliseconds(tick)? / 1000).map_err(|_| TickTimeError::TickOverflow)
+    }
+}
+
+/// Number of ticks needed to cover the given number of milliseconds, rounded up
+fn ticks_for_milliseconds(options: &TickTimeOptions, milliseconds: u128) -> Result<usize, TickTimeError> {
+    let rate = get_tick_rate(options);
+    let divisor = rate.milliseconds as u128;
+    milliseconds
+        .checked_mul(rate.ticks as u128)
+        .map(|dividend| dividend / divisor + u128::from(dividend % divisor != 0))
+        .and_then(|ticks| usize::try_from(ticks).ok())
+        .ok_or(TickTimeError::TickOverflow)
+}
+
+/// Compute the values of the date and time `total_seconds` after the beginning of the calendar
+fn compute_values(options: &TickTimeOptions, total_seconds: usize) -> TickTimeValue {
+    match options.tick_time_type {
+        TickTimeType::EarthLike { .. } => compute_earthlike_time(options, total_seconds),
+        TickTimeType::Custom { .. } => compute_custom_date_time_values(options, total_seconds),
+    }
+}
+
+fn compute_earthlike_time(options: &TickTimeOptions, total_seconds: usize) -> TickTimeValue {
+    let mut values = TickTimeValue::default();
+    if let TickTimeType::EarthLike {
+        month_type,
+        ..
+    } = &options.tick_time_type
+    {
+        values.second = total_seconds % 60;
+        values.minute = (total_seconds / 60) % 60;
+        values.hour = (total_seconds / 3600) % 24;
+        let total_days = total_seconds / 86400;
+        let (day, week, month, season, year) = match month_type {
+            EarthLikeMonthType::Lunar => compute_lunar_calendar_value(total_days),
+            EarthLikeMonthType::Real => compute_real_calendar_value(total_days)
+        };
+        values.day = day;
+        values.month = month;
+        values.week = week;
+        values.season = season;
+        values.year = year;
+        values.weekday = compute_weekday(options, total_days);
+    }
+    values
+}
+
+fn compute_custom_date_time_values(options: &TickTimeOptions, total_seconds: usize) -> TickTimeValue {
+    let mut values = TickTimeValue::default();
+    if let TickTimeType::Custom {
+        hours_in_a_day, months_durations, seasons_durations, week_duration, ..
+    } = &options.tick_time_type
+    {
+        values.second = total_seconds % 60;
+        values.minute = (total_seconds / 60) % 60;
+        values.hour = (total_seconds / 3600) % hours_in_a_day;
+        let total_days = total_seconds / 3600 / hours_in_a_day;
+        let year_duration: usize = months_durations.iter().sum();
+        let (day, week, month, season, year) = {
+            let (day, current_year) = (total_days % year_duration, total_days / year_duration);
+
+            let (month, day_of_month) = find_correct_index_and_day_in_section(
+                day,
+                months_durations.len(),
+                months_durations,
+            );
+
+            let (season, _) = find_correct_index_and_day_in_section(
+                (day + year_duration - options.seasons_offset) % year_duration,
+                seasons_durations.len(),
+                seasons_durations,
+            );
+
+            (day_of_month, day / week_duration, month, season, current_year)
+        };
+        values.day = day;
+        values.week = week;
+        values.month = month;
+        values.season = season;
+        values.year = year;
+        values.weekday = compute_weekday(options, total_days);
     }
+    values
+}
+
+/// Serialized form of a `TickTime`. Computed values are not saved, they are computed again
+/// through `TickTime::init` on deserialization so invalid options are rejected.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TickTimeState {
+    current_tick: usize,
+    options: TickTimeOptions,
+    #[serde(default)]
+    scale_segments: Vec<ScaleSegment>,
+    #[serde(default)]
+    paused_scale: Option<TimeScale>,
+    #[serde(default)]
+    alarms: AlarmScheduler,
+}
 
-    fn compute_earthlike_time(&mut self) {
-        if let TickTimeType::EarthLike {
-            seconds_per_tick,
-            month_type,
-        } = &self.options.tick_time_type
-        {
-            let total_seconds = self.current_tick * seconds_per_tick;
-            self.values.second = total_seconds % 60;
-            self.values.minute = (total_seconds / 60) % 60;
-            self.values.hour = (total_seconds / 3600) % 24;
-            let total_days = total_seconds / 86400;
-            let (day, week, month, season, year) = match month_type {
-                EarthLikeMonthType::Lunar => compute_lunar_calendar_value(total_days),
-                EarthLikeMonthType::Real => compute_real_calendar_value(total_days)
-            };
-            self.values.day = day;
-            self.values.month = month;
-            self.values.week = week;
-            self.values.season = season;
-            self.values.year = year;
+#[cfg(feature = "serde")]
+impl From<TickTime> for TickTimeState {
+    fn from(tick_time: TickTime) -> Self {
+        TickTimeState {
+            current_tick: tick_time.current_tick,
+            options: tick_time.options,
+            scale_segments: tick_time.scale_segments,
+            paused_scale: tick_time.paused_scale,
+            alarms: tick_time.alarms,
         }
     }
+}
 
-    fn compute_custom_date_time_values(&mut self) {
-        if let TickTimeType::Custom {
-            seconds_per_tick, hours_in_a_day, months_durations, seasons_durations, week_duration
-        } = &self.options.tick_time_type
-        {
-            let total_seconds = self.current_tick * seconds_per_tick;
-            self.values.second = total_seconds % 60;
-            self.values.minute = (total_seconds / 60) % 60;
-            self.values.hour = (total_seconds / 3600) % hours_in_a_day;
-            let total_days = total_seconds / 3600 / hours_in_a_day;
-            let year_duration: usize = months_durations.iter().sum();
-            let (day, week, month, season, year) = {
-                let (day, current_year) = (total_days % year_duration, total_days / year_duration);
-
-                let (month, day_of_month) = find_correct_index_and_day_in_section(
-                    day,
-                    months_durations.len(),
-                    months_durations,
-                );
-
-                let (season, _) = find_correct_index_and_day_in_section(
-                    day,
-                    seasons_durations.len(),
-                    seasons_durations,
-                );
-
-                (day_of_month, day / week_duration, month, season % 4, current_year)
-            };
-            self.values.day = day;
-            self.values.week = week;
-            self.values.month = month;
-            self.values.season = season;
-            self.values.year = year;
+#[cfg(feature = "serde")]
+impl TryFrom<TickTimeState> for TickTime {
+    type Error = TickTimeError;
+
+    fn try_from(state: TickTimeState) -> Result<Self, Self::Error> {
+        let mut tick_time = TickTime::init(state.current_tick, state.options)?;
+        if !state.scale_segments.is_empty() {
+            let first = &state.scale_segments[0];
+            let starts_at_zero = first.start_tick == 0 && first.start_milliseconds == 0;
+            if !starts_at_zero || state.scale_segments.iter().any(|segment| segment.scale.denominator == 0) {
+                return Err(TickTimeError::InvalidScaleSegments);
+            }
+            // Each segment starts where the previous one brought the date
+            let rate = get_tick_rate(&tick_time.options);
+            let continuous = state.scale_segments.windows(2).all(|pair| {
+                pair[0].start_tick < pair[1].start_tick
+                    && pair[0].elapsed_milliseconds_at(rate, pair[1].start_tick) == Some(pair[1].start_milliseconds)
+            });
+            if !continuous {
+                return Err(TickTimeError::InvalidScaleSegments);
+            }
+            tick_time.scale_segments = state.scale_segments;
+            tick_time.apply_current_tick()?;
         }
+        if state.paused_scale.is_some_and(|scale| scale.denominator == 0) {
+            return Err(TickTimeError::ZeroTimeScaleDenominator);
+        }
+        // A paused time is stopped by a scale of 0 applied to the current tick
+        let stopped = tick_time.scale_segments.last().is_some_and(|segment| {
+            segment.scale.numerator == 0 && segment.start_tick <= tick_time.current_tick
+        });
+        if state.paused_scale.is_some() && !stopped {
+            return Err(TickTimeError::InvalidScaleSegments);
+        }
+        tick_time.paused_scale = state.paused_scale;
+        tick_time.restore_alarms(state.alarms)?;
+        Ok(tick_time)
     }
 }
 
 impl fmt::Display for TickTime {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Tick time: [ Current tick: {}, Year: {}, Season: {}, Week: {} Month: {}, Day: {}, Hour: {}, Minute: {}, Second: {}]",
-               self.current_tick, self.year(), self.season(), self.week(), self.month(), self.day(), self.hour(), self.minute(), self.second())
+        let name_or_value = |name: Option<&str>, value: usize| name.map_or_else(|| value.to_string(), str::to_string);
+        write!(f, "Tick time: [ Current tick: {}, Year: {}, Season: {}, Week: {} Month: {}, Day: {}, Weekday: {}, Hour: {}, Minute: {}, Second: {}]",
+               self.current_tick, self.year(), name_or_value(self.season_name(), self.season()), self.week(),
+               name_or_value(self.month_name(), self.month()), self.day(), name_or_value(self.weekday_name(), self.weekday()),
+               self.hour(), self.minute(), self.second())
     }
 }
 
@@ -332,44 +835,278 @@ fn get_season_duration(is_leap_year: bool) -> Vec<usize> {
     vec![if is_leap_year { 81 } else { 80 }, 92, 92, 91]
 }
 
-fn verify_tick_time_type_values(tick_time_type: &TickTimeType) -> Result<(), &'static str> {
-    match tick_time_type {
-        TickTimeType::EarthLike {
-            seconds_per_tick, ..
-        } => {
-            if *seconds_per_tick == 0 {
-                return Err("The minimum value for EarthLike::seconds_per_tick is 1");
-            }
-        }
+fn verify_options(options: &TickTimeOptions) -> Result<(), TickTimeError> {
+    match options.tick_rate {
+        Some(rate) if rate.milliseconds == 0 || rate.ticks == 0 => return Err(TickTimeError::ZeroTickRate),
+        None if get_seconds_per_tick(&options.tick_time_type) == 0 => return Err(TickTimeError::ZeroSecondsPerTick),
+        _ => {}
+    }
+    verify_tick_time_type_values(options)?;
+    verify_calendar_names(options)?;
+    verify_epoch_values(&options.tick_time_type, &options.epoch)
+}
+
+fn verify_tick_time_type_values(options: &TickTimeOptions) -> Result<(), TickTimeError> {
+    match &options.tick_time_type {
+        TickTimeType::EarthLike { .. } => {}
         TickTimeType::Custom {
-            seconds_per_tick, hours_in_a_day: _, months_durations, seasons_durations, ..
+            hours_in_a_day, months_durations, seasons_durations, week_duration, ..
         } => {
-            if *seconds_per_tick == 0 {
-                return Err("The minimum value for Custom::seconds_per_tick is 1");
+            if *hours_in_a_day == 0 {
+                return Err(TickTimeError::ZeroHoursInDay);
+            }
+            if *week_duration == 0 {
+                return Err(TickTimeError::ZeroWeekDuration);
+            }
+            if months_durations.is_empty() {
+                return Err(TickTimeError::EmptyMonths);
+            }
+            if let Some(month) = months_durations.iter().position(|duration| *duration == 0) {
+                return Err(TickTimeError::ZeroMonthDuration { month });
             }
-            if months_durations.iter().sum::<usize>() != seasons_durations.iter().sum::<usize>() {
-                return Err("The sum of values of Custom::months_durations and Custom::season_duration should be the same to keep consistent");
+            if seasons_durations.is_empty() {
+                return Err(TickTimeError::EmptySeasons);
+            }
+            if let Some(season) = seasons_durations.iter().position(|duration| *duration == 0) {
+                return Err(TickTimeError::ZeroSeasonDuration { season });
+            }
+            let (months_total, seasons_total) = (months_durations.iter().sum::<usize>(), seasons_durations.iter().sum::<usize>());
+            if months_total != seasons_total {
+                return Err(TickTimeError::MonthSeasonSumMismatch { months_total, seasons_total });
+            }
+            if options.seasons_offset >= months_total {
+                return Err(TickTimeError::SeasonsOffsetOutOfRange { seasons_offset: options.seasons_offset, year_duration: months_total });
             }
         }
     }
     Ok(())
 }
 
+fn verify_calendar_names(options: &TickTimeOptions) -> Result<(), TickTimeError> {
+    let (months, seasons, weekdays) = match &options.tick_time_type {
+        TickTimeType::EarthLike { .. } => (12, 4, 7),
+        TickTimeType::Custom { months_durations, seasons_durations, week_duration, .. } =>
+            (months_durations.len(), seasons_durations.len(), *week_duration),
+    };
+    if let Some(names) = &options.names {
+        if !names.months.is_empty() && names.months.len() != months {
+            return Err(TickTimeError::MonthNamesCountMismatch { expected: months, actual: names.months.len() });
+        }
+        if !names.seasons.is_empty() && names.seasons.len() != seasons {
+            return Err(TickTimeError::SeasonNamesCountMismatch { expected: seasons, actual: names.seasons.len() });
+        }
+        if !names.weekdays.is_empty() && names.weekdays.len() != weekdays {
+            return Err(TickTimeError::WeekdayNamesCountMismatch { expected: weekdays, actual: names.weekdays.len() });
+        }
+    }
+    Ok(())
+}
+
+fn verify_epoch_values(tick_time_type: &TickTimeType, epoch: &TickTimeEpoch) -> Result<(), TickTimeError> {
+    verify_date_values(tick_time_type, &epoch_date(epoch))?;
+    let week_duration = get_week_duration(tick_time_type);
+    if epoch.weekday >= week_duration {
+        return Err(TickTimeError::WeekdayOutOfRange { weekday: epoch.weekday, week_duration });
+    }
+    // The seconds of the ticks are counted from the beginning of the calendar
+    get_total_seconds(tick_time_type, &epoch_date(epoch))?;
+    Ok(())
+}
+
+fn verify_date_values(tick_time_type: &TickTimeType, date: &GameDateTime) -> Result<(), TickTimeError> {
+    let months_durations = get_months_durations_of_year(tick_time_type, date.year);
+    if date.month >= months_durations.len() {
+        return Err(TickTimeError::MonthOutOfRange { month: date.month, months_in_year: months_durations.len() });
+    }
+    if date.day >= months_durations[date.month] {
+        return Err(TickTimeError::DayOutOfRange { day: date.day, days_in_month: months_durations[date.month] });
+    }
+    let hours_in_a_day = get_hours_in_a_day(tick_time_type);
+    if date.hour >= hours_in_a_day {
+        return Err(TickTimeError::HourOutOfRange { hour: date.hour, hours_in_a_day });
+    }
+    if date.minute >= 60 {
+        return Err(TickTimeError::MinuteOutOfRange { minute: date.minute });
+    }
+    if date.second >= 60 {
+        return Err(TickTimeError::SecondOutOfRange { second: date.second });
+    }
+    Ok(())
+}
+
+/// Earliest tick at or after the given date, without time scale
+fn tick_for_date(options: &TickTimeOptions, date: &GameDateTime) -> Result<usize, TickTimeError> {
+    ticks_for_milliseconds(options, date_elapsed_milliseconds(options, date)?)
+}
+
+/// Number of in game milliseconds between the epoch and a date
+fn date_elapsed_milliseconds(options: &TickTimeOptions, date: &GameDateTime) -> Result<u128, TickTimeError> {
+    verify_date_values(&options.tick_time_type, date)?;
+    let (total_seconds, epoch_seconds) = (get_total_seconds(&options.tick_time_type, date)?, epoch_total_seconds(options));
+    if total_seconds < epoch_seconds {
+        return Err(TickTimeError::DateBeforeEpoch);
+    }
+    Ok((total_seconds - epoch_seconds) as u128 * 1000)
+}
+
+/// Verify a date given by the user and fill its season, week and weekday
+fn complete_date(options: &TickTimeOptions, date: &GameDateTime) -> Result<GameDateTime, TickTimeError> {
+    verify_date_values(&options.tick_time_type, date)?;
+    Ok(compute_values(options, get_total_seconds(&options.tick_time_type, date)?).date())
+}
+
+fn epoch_date(epoch: &TickTimeEpoch) -> GameDateTime {
+    GameDateTime {
+        year: epoch.year,
+        month: epoch.month,
+        day: epoch.day,
+        hour: epoch.hour,
+        minute: epoch.minute,
+        second: epoch.second,
+        ..Default::default()
+    }
+}
+
+/// Number of seconds between the beginning of the calendar and the epoch of verified options
+fn epoch_total_seconds(options: &TickTimeOptions) -> usize {
+    get_total_seconds(&options.tick_time_type, &epoch_date(&options.epoch)).expect("The epoch is verified with the options")
+}
+
+/// Number of days between the beginning of the calendar and the epoch of verified options
+fn epoch_total_days(options: &TickTimeOptions) -> usize {
+    let epoch = &options.epoch;
+    get_total_days(&options.tick_time_type, epoch.year, epoch.month, epoch.day).expect("The epoch is verified with the options")
+}
+
+/// Number of seconds between the beginning of the calendar and a valid date. Returns an error
+/// when the date is too far to be counted.
+fn get_total_seconds(tick_time_type: &TickTimeType, date: &GameDateTime) -> Result<usize, TickTimeError> {
+    get_total_days(tick_time_type, date.year, date.month, date.day)?
+        .checked_mul(get_hours_in_a_day(tick_time_type))
+        .and_then(|hours| hours.checked_add(date.hour))
+        .and_then(|hours| hours.checked_mul(3600))
+        .and_then(|seconds| seconds.checked_add(date.minute * 60 + date.second))
+        .ok_or(TickTimeError::TickOverflow)
+}
+
+/// Number of days between the beginning of the calendar and a valid date. Returns an error
+/// when the date is too far to be counted.
+fn get_total_days(tick_time_type: &TickTimeType, year: usize, month: usize, day: usize) -> Result<usize, TickTimeError> {
+    get_days_before_year(tick_time_type, year)
+        .and_then(|days| days.checked_add(get_months_durations_of_year(tick_time_type, year)[..month].iter().sum::<usize>() + day))
+        .ok_or(TickTimeError::TickOverflow)
+}
+
+/// Weekdays are counted from the epoch so they keep going across years
+fn compute_weekday(options: &TickTimeOptions, total_days: usize) -> usize {
+    let week_duration = get_week_duration(&options.tick_time_type);
+    let offset = options.epoch.weekday + week_duration - epoch_total_days(options) % week_duration;
+    (total_days + offset) % week_duration
+}
+
+fn get_week_duration(tick_time_type: &TickTimeType) -> usize {
+    match tick_time_type {
+        TickTimeType::EarthLike { .. } => 7,
+        TickTimeType::Custom { week_duration, .. } => *week_duration,
+    }
+}
+
+/// Name of the unit at `index`, using the configured names first, then the EarthLike default ones
+fn get_unit_name<'a>(
+    options: &'a TickTimeOptions,
+    unit_names: fn(&CalendarNames) -> &Vec<String>,
+    earthlike_names: &[&'static str],
+    index: usize,
+) -> Option<&'a str> {
+    get_unit_names(options, unit_names, earthlike_names).and_then(|names| names.get(index).copied())
+}
+
+/// All the names of a unit, using the configured names first, then the EarthLike default ones
+fn get_unit_names<'a>(
+    options: &'a TickTimeOptions,
+    unit_names: fn(&CalendarNames) -> &Vec<String>,
+    earthlike_names: &[&'static str],
+) -> Option<Vec<&'a str>> {
+    let is_earthlike = matches!(options.tick_time_type, TickTimeType::EarthLike { .. });
+    match options.names.as_ref().map(unit_names) {
+        Some(configured) if !configured.is_empty() => Some(configured.iter().map(String::as_str).collect()),
+        _ if is_earthlike => Some(earthlike_names.to_vec()),
+        _ => None,
+    }
+}
+
+fn get_seconds_per_tick(tick_time_type: &TickTimeType) -> usize {
+    match tick_time_type {
+        TickTimeType::EarthLike { seconds_per_tick, .. } => *seconds_per_tick,
+        TickTimeType::Custom { seconds_per_tick, .. } => *seconds_per_tick,
+    }
+}
+
+/// Duration of the ticks, from the tick rate or the seconds per tick
+fn get_tick_rate(options: &TickTimeOptions) -> TickRate {
+    options.tick_rate.unwrap_or_else(|| TickRate::seconds_per_ticks(get_seconds_per_tick(&options.tick_time_type), 1))
+}
+
+fn get_hours_in_a_day(tick_time_type: &TickTimeType) -> usize {
+    match tick_time_type {
+        TickTimeType::EarthLike { .. } => 24,
+        TickTimeType::Custom { hours_in_a_day, .. } => *hours_in_a_day,
+    }
+}
+
+fn get_months_durations_of_year(tick_time_type: &TickTimeType, year: usize) -> Vec<usize> {
+    match tick_time_type {
+        TickTimeType::EarthLike { month_type: EarthLikeMonthType::Lunar, .. } => vec![LUNAR_MONTH_DURATION; 12],
+        TickTimeType::EarthLike { month_type: EarthLikeMonthType::Real, .. } => get_month_duration(is_leap_year(year)),
+        TickTimeType::Custom { months_durations, .. } => months_durations.clone(),
+    }
+}
+
+/// Number of days between the beginning of the calendar and the first day of `year`, None when
+/// it can't be counted
+fn get_days_before_year(tick_time_type: &TickTimeType, year: usize) -> Option<usize> {
+    match tick_time_type {
+        TickTimeType::EarthLike { month_type: EarthLikeMonthType::Lunar, .. } => year.checked_mul(LUNAR_YEAR_DURATION),
+        // A year never lasts more than 366 days
+        TickTimeType::EarthLike { month_type: EarthLikeMonthType::Real, .. } => year.checked_mul(366).map(|_| days_before_real_year(year)),
+        TickTimeType::Custom { months_durations, .. } => year.checked_mul(months_durations.iter().sum::<usize>()),
+    }
+}
+
 fn normalize_total_day_to_year_information(total_days: usize) -> (usize, usize, bool) {
-    let base_4_year_days = total_days % 1461;
-    let base_4_year_start = (total_days / 1461) * 4;
-    match base_4_year_days {
-        0..=365 => (base_4_year_days, base_4_year_start, true),
-        366..=730 => (base_4_year_days - 366, base_4_year_start + 1, false),
-        731..=1095 => (base_4_year_days - 731, base_4_year_start + 2, false),
-        _ => (base_4_year_days - 1095, base_4_year_start + 3, false),
+    let base_400_year_days = total_days % GREGORIAN_CYCLE_DURATION;
+    let base_400_year_start = (total_days / GREGORIAN_CYCLE_DURATION) * 400;
+
+    // The estimation can be one year off because leap days are not evenly spread in the cycle
+    let mut year = base_400_year_days * 400 / GREGORIAN_CYCLE_DURATION;
+    while days_before_real_year(year + 1) <= base_400_year_days {
+        year += 1;
+    }
+    while days_before_real_year(year) > base_400_year_days {
+        year -= 1;
     }
+
+    (
+        base_400_year_days - days_before_real_year(year),
+        base_400_year_start + year,
+        is_leap_year(year),
+    )
+}
+
+/// Number of days between the first day of the year 0 and the first day of `year`,
+/// following the proleptic Gregorian calendar (year 0 is a leap year).
+fn days_before_real_year(year: usize) -> usize {
+    365 * year + (year + 3) / 4 - (year + 99) / 100 + (year + 399) / 400
+}
+
+fn is_leap_year(year: usize) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
 }
 
 fn find_correct_index_and_day_in_section(
     day: usize,
     max: usize,
-    array: &Vec<usize>,
+    array: &[usize],
 ) -> (usize, usize) {
     let (mut day_counter, mut stop, mut index) = (day, false, 0);
     while !stop && index < max {
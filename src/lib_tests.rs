@@ -1,3 +1,121 @@
+// The tests kept from the first version compare booleans and match on options
+#![allow(clippy::bool_assert_comparison, clippy::redundant_pattern_matching)]
+
+#[cfg(test)]
+mod test_helpers {
+    use crate::*;
+    use crate::date::GameDateTime;
+
+    pub(super) fn lunar_options(seconds_per_tick: usize, compute_events: bool) -> TickTimeOptions {
+        TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick, month_type: EarthLikeMonthType::Lunar },
+            compute_events,
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn lunar_tick_time(seconds_per_tick: usize, compute_events: bool) -> TickTime {
+        TickTime::init(0, lunar_options(seconds_per_tick, compute_events)).unwrap()
+    }
+
+    pub(super) fn real_tick_time(seconds_per_tick: usize, compute_events: bool) -> TickTime {
+        TickTime::init(0, TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick, month_type: EarthLikeMonthType::Real },
+            compute_events,
+            ..Default::default()
+        }).unwrap()
+    }
+
+    pub(super) fn custom_options() -> TickTimeOptions {
+        TickTimeOptions {
+            tick_time_type: TickTimeType::Custom {
+                seconds_per_tick: 3600,
+                hours_in_a_day: 12,
+                months_durations: vec![10, 5],
+                seasons_durations: vec![15],
+                week_duration: 5,
+            },
+            compute_events: false,
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn two_season_custom_options() -> TickTimeOptions {
+        TickTimeOptions {
+            tick_time_type: TickTimeType::Custom {
+                seconds_per_tick: 3600,
+                hours_in_a_day: 12,
+                months_durations: vec![10, 5],
+                seasons_durations: vec![8, 7],
+                week_duration: 5,
+            },
+            compute_events: false,
+            ..Default::default()
+        }
+    }
+
+    /// A custom calendar whose year lasts a single week
+    pub(super) fn week_year_custom_tick_time(seconds_per_tick: usize) -> TickTime {
+        TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::Custom {
+                    seconds_per_tick,
+                    hours_in_a_day: 12,
+                    months_durations: vec![3, 4],
+                    seasons_durations: vec![7],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        ).unwrap()
+    }
+
+    pub(super) fn named_custom_tick_time(total_days: usize, names: Option<CalendarNames>) -> Result<TickTime, TickTimeError> {
+        TickTime::init(
+            total_days,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::Custom {
+                    seconds_per_tick: 3600 * 24,
+                    hours_in_a_day: 24,
+                    months_durations: vec![6, 6],
+                    seasons_durations: vec![4, 4, 4],
+                    week_duration: 3,
+                },
+                compute_events: false,
+                names,
+                ..Default::default()
+            },
+        )
+    }
+
+    pub(super) fn lunar_options_at(seconds_per_tick: usize, epoch: TickTimeEpoch) -> TickTimeOptions {
+        TickTimeOptions { epoch, ..lunar_options(seconds_per_tick, false) }
+    }
+
+    pub(super) fn real_options_at(seconds_per_tick: usize, epoch: TickTimeEpoch) -> TickTimeOptions {
+        TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick, month_type: EarthLikeMonthType::Real },
+            compute_events: false,
+            epoch,
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn real_options_with_rate(tick_rate: Option<TickRate>) -> TickTimeOptions {
+        TickTimeOptions { tick_rate, ..real_options_at(3600, Default::default()) }
+    }
+
+    pub(super) fn real_tick_time_at(seconds_per_tick: usize, epoch: TickTimeEpoch) -> TickTime {
+        TickTime::init(0, real_options_at(seconds_per_tick, epoch)).unwrap()
+    }
+
+    pub(super) fn date(year: usize, month: usize, day: usize) -> GameDateTime {
+        GameDateTime { year, month, day, ..Default::default() }
+    }
+}
+
 #[cfg(test)]
 mod general_ticktime_tests {
     use crate::*;
@@ -12,6 +130,7 @@ mod general_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -29,6 +148,7 @@ mod general_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -57,10 +177,11 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             }
             ,
         );
-        assert_eq!(true, tick_time_result.is_err());
+        assert_eq!(TickTimeError::ZeroSecondsPerTick, tick_time_result.unwrap_err());
     }
 
     #[test]
@@ -74,6 +195,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         );
         assert_eq!(true, tick_time_result.is_ok());
@@ -91,6 +213,7 @@ mod earthlike_ticktime_tests {
                 }
                 ,
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -108,6 +231,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -128,6 +252,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -145,6 +270,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -165,6 +291,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -186,6 +313,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -202,6 +330,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -222,6 +351,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -238,6 +368,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -258,6 +389,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -274,6 +406,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -294,6 +427,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -310,6 +444,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Lunar,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -331,6 +466,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Real,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -348,6 +484,7 @@ mod earthlike_ticktime_tests {
                     month_type: EarthLikeMonthType::Real,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         )
             .unwrap();
@@ -356,12 +493,232 @@ mod earthlike_ticktime_tests {
         }
         assert_eq!((1, 2, 35, 8, 7, 0, 0, 0), tick_time.values());
     }
+
+    fn real_calendar_values_at_day(total_days: usize) -> (usize, usize, usize, usize, usize, usize, usize, usize) {
+        TickTime::init(
+            total_days,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::EarthLike {
+                    seconds_per_tick: 3600 * 24,
+                    month_type: EarthLikeMonthType::Real,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        )
+            .unwrap()
+            .values()
+    }
+
+    #[test]
+    fn earthlike_ticktime_should_not_compute_1900_as_leap_year() {
+        // 1900-02-28
+        assert_eq!((1900, 0, 8, 1, 27, 0, 0, 0), real_calendar_values_at_day(694019));
+        // 1900-03-01
+        assert_eq!((1900, 0, 8, 2, 0, 0, 0, 0), real_calendar_values_at_day(694020));
+        // 1900-12-31
+        assert_eq!((1900, 0, 52, 11, 30, 0, 0, 0), real_calendar_values_at_day(694325));
+    }
+
+    #[test]
+    fn earthlike_ticktime_should_compute_2000_as_leap_year() {
+        // 2000-01-01
+        assert_eq!((2000, 0, 0, 0, 0, 0, 0, 0), real_calendar_values_at_day(730485));
+        // 2000-02-29
+        assert_eq!((2000, 0, 8, 1, 28, 0, 0, 0), real_calendar_values_at_day(730544));
+        // 2000-03-01
+        assert_eq!((2000, 0, 8, 2, 0, 0, 0, 0), real_calendar_values_at_day(730545));
+        // 2000-12-31
+        assert_eq!((2000, 0, 52, 11, 30, 0, 0, 0), real_calendar_values_at_day(730850));
+    }
+
+    #[test]
+    fn earthlike_ticktime_should_not_compute_2100_as_leap_year() {
+        // 2100-01-01
+        assert_eq!((2100, 0, 0, 0, 0, 0, 0, 0), real_calendar_values_at_day(767010));
+        // 2100-03-01
+        assert_eq!((2100, 0, 8, 2, 0, 0, 0, 0), real_calendar_values_at_day(767069));
+        // 2100-12-31
+        assert_eq!((2100, 0, 52, 11, 30, 0, 0, 0), real_calendar_values_at_day(767374));
+    }
+
+    #[test]
+    fn earthlike_ticktime_should_compute_real_calendar_over_a_400_years_cycle() {
+        assert_eq!((400, 0, 0, 0, 0, 0, 0, 0), real_calendar_values_at_day(146_097));
+        assert_eq!((399, 0, 52, 11, 30, 0, 0, 0), real_calendar_values_at_day(146_096));
+    }
 }
 
 #[cfg(test)]
 mod custom_ticktime_tests {
     use crate::*;
 
+    #[test]
+    fn init_custom_with_zero_seconds_per_tick_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 0,
+                    hours_in_a_day: 24,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![20],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::ZeroSecondsPerTick, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_custom_with_zero_hours_in_a_day_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 0,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![20],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::ZeroHoursInDay, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_custom_with_zero_week_duration_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 24,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![20],
+                    week_duration: 0,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::ZeroWeekDuration, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_custom_with_empty_months_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 24,
+                    months_durations: vec![],
+                    seasons_durations: vec![],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::EmptyMonths, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_custom_with_zero_length_month_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 24,
+                    months_durations: vec![10, 0, 10],
+                    seasons_durations: vec![20],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::ZeroMonthDuration { month: 1 }, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_custom_with_empty_seasons_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 24,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::EmptySeasons, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_custom_with_zero_length_season_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 24,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![0, 20],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::ZeroSeasonDuration { season: 0 }, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_custom_with_different_months_and_seasons_sums_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 24,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![5, 10],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        );
+        let error = tick_time_result.unwrap_err();
+        assert_eq!(TickTimeError::MonthSeasonSumMismatch { months_total: 20, seasons_total: 15 }, error);
+        assert_eq!(
+            "The sum of months_durations (20) and seasons_durations (15) should be the same to keep consistent",
+            error.to_string()
+        );
+    }
+
     #[test]
     fn custom_ticktime_should_compute_year() {
         let mut tick_time = TickTime::init(
@@ -376,6 +733,7 @@ mod custom_ticktime_tests {
                     week_duration: 7,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         ).unwrap();
 
@@ -400,6 +758,7 @@ mod custom_ticktime_tests {
                     week_duration: 7,
                 },
                 compute_events: false,
+                ..Default::default()
             },
         ).unwrap();
 
@@ -409,6 +768,63 @@ mod custom_ticktime_tests {
 
         assert_eq!((1, 0, 0, 3, 0, 0, 0, 0), tick_time.values());
     }
+
+    fn custom_season_at_day(total_days: usize, seasons_durations: Vec<usize>, seasons_offset: usize) -> usize {
+        TickTime::init(
+            total_days,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 3600 * 24,
+                    hours_in_a_day: 24,
+                    months_durations: vec![seasons_durations.iter().sum()],
+                    seasons_durations,
+                    week_duration: 7,
+                },
+                compute_events: false,
+                seasons_offset,
+                ..Default::default()
+            },
+        ).unwrap().season()
+    }
+
+    #[test]
+    fn custom_ticktime_should_compute_more_than_four_seasons() {
+        assert_eq!(0, custom_season_at_day(9, vec![10; 6], 0));
+        assert_eq!(4, custom_season_at_day(45, vec![10; 6], 0));
+        assert_eq!(5, custom_season_at_day(55, vec![10; 6], 0));
+        assert_eq!(0, custom_season_at_day(60, vec![10; 6], 0));
+    }
+
+    #[test]
+    fn custom_ticktime_should_compute_season_spanning_the_new_year() {
+        assert_eq!(0, custom_season_at_day(11, vec![3, 3, 3, 3], 11));
+        assert_eq!(0, custom_season_at_day(12, vec![3, 3, 3, 3], 11));
+        assert_eq!(0, custom_season_at_day(13, vec![3, 3, 3, 3], 11));
+        assert_eq!(1, custom_season_at_day(14, vec![3, 3, 3, 3], 11));
+        assert_eq!(3, custom_season_at_day(22, vec![3, 3, 3, 3], 11));
+    }
+
+    #[test]
+    fn init_custom_with_seasons_offset_outside_of_year_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type:
+                TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 24,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![20],
+                    week_duration: 7,
+                },
+                compute_events: false,
+                seasons_offset: 20,
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::SeasonsOffsetOutOfRange { seasons_offset: 20, year_duration: 20 }, tick_time_result.unwrap_err());
+    }
 }
 
 #[cfg(test)]
@@ -422,6 +838,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 123, month_type: EarthLikeMonthType::Real },
                 compute_events: false,
+                ..Default::default()
             }).unwrap();
 
         if let Some(_) = ticktime.tick() {
@@ -435,6 +852,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 123, month_type: EarthLikeMonthType::Real },
                 compute_events: true,
+                ..Default::default()
             }).unwrap();
 
         if let None = ticktime.tick() {
@@ -448,6 +866,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Real },
                 compute_events: true,
+                ..Default::default()
             }).unwrap();
 
         if let Some(TickTimeEvent{second_update: Some(second_event), ..}) = ticktime.tick() {
@@ -464,6 +883,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 60, month_type: EarthLikeMonthType::Real },
                 compute_events: true,
+                ..Default::default()
             }).unwrap();
 
         if let Some(TickTimeEvent{second_update: Some(second_event), minute_update: Some(minute_event), ..}) = ticktime.tick() {
@@ -482,6 +902,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600, month_type: EarthLikeMonthType::Real },
                 compute_events: true,
+                ..Default::default()
             }).unwrap();
 
         if let Some(TickTimeEvent{  second_update: Some(second_event),
@@ -505,6 +926,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24, month_type: EarthLikeMonthType::Real },
                 compute_events: true,
+                ..Default::default()
             }).unwrap();
 
         if let Some(TickTimeEvent{  second_update: Some(second_event),
@@ -531,6 +953,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24 * 7, month_type: EarthLikeMonthType::Real },
                 compute_events: true,
+                ..Default::default()
             }).unwrap();
 
         if let Some(TickTimeEvent{
@@ -549,6 +972,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24 * 366, month_type: EarthLikeMonthType::Real },
                 compute_events: true,
+                ..Default::default()
             }).unwrap();
 
         if let Some(TickTimeEvent{  second_update: Some(second_event),
@@ -582,6 +1006,7 @@ mod event_tests {
             0, TickTimeOptions {
                 tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24 * 31, month_type: EarthLikeMonthType::Real },
                 compute_events: true,
+                ..Default::default()
             }).unwrap();
 
         if let Some(TickTimeEvent{  second_update: Some(second_event),
@@ -606,4 +1031,1897 @@ mod event_tests {
             panic!("No event returned but it should");
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod epoch_tests {
+    use crate::*;
+
+    #[test]
+    fn lunar_ticktime_should_start_at_epoch() {
+        let mut tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24, month_type: EarthLikeMonthType::Lunar },
+                compute_events: false,
+                epoch: TickTimeEpoch { year: 1203, month: 2, day: 13, hour: 6, ..Default::default() },
+                ..Default::default()
+            },
+        ).unwrap();
+        assert_eq!((1203, 0, 10, 2, 13, 6, 0, 0), tick_time.values());
+
+        tick_time.tick();
+        assert_eq!((1203, 0, 10, 2, 14, 6, 0, 0), tick_time.values());
+
+        for _ in 0..16 {
+            tick_time.tick();
+        }
+        assert_eq!((1203, 1, 12, 3, 0, 6, 0, 0), tick_time.values());
+    }
+
+    #[test]
+    fn real_ticktime_should_use_leap_year_of_epoch() {
+        let mut tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24, month_type: EarthLikeMonthType::Real },
+                compute_events: false,
+                epoch: TickTimeEpoch { year: 2000, month: 1, day: 27, ..Default::default() },
+                ..Default::default()
+            },
+        ).unwrap();
+        assert_eq!((2000, 0, 8, 1, 27, 0, 0, 0), tick_time.values());
+
+        tick_time.tick();
+        assert_eq!((2000, 0, 8, 1, 28, 0, 0, 0), tick_time.values());
+
+        tick_time.tick();
+        assert_eq!((2000, 0, 8, 2, 0, 0, 0, 0), tick_time.values());
+    }
+
+    #[test]
+    fn custom_ticktime_should_start_at_epoch() {
+        let mut tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 12,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![20],
+                    week_duration: 5,
+                },
+                compute_events: false,
+                epoch: TickTimeEpoch { year: 3, month: 1, day: 9, hour: 11, minute: 59, second: 59, weekday: 0 },
+                ..Default::default()
+            },
+        ).unwrap();
+        assert_eq!((3, 0, 3, 1, 9, 11, 59, 59), tick_time.values());
+
+        tick_time.tick();
+        assert_eq!((4, 0, 0, 0, 0, 0, 0, 0), tick_time.values());
+    }
+
+    #[test]
+    fn init_with_day_outside_of_epoch_month_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Real },
+                compute_events: false,
+                epoch: TickTimeEpoch { year: 1900, month: 1, day: 28, ..Default::default() },
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::DayOutOfRange { day: 28, days_in_month: 28 }, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_with_hour_outside_of_custom_day_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 12,
+                    months_durations: vec![10, 10],
+                    seasons_durations: vec![20],
+                    week_duration: 5,
+                },
+                compute_events: false,
+                epoch: TickTimeEpoch { hour: 12, ..Default::default() },
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::HourOutOfRange { hour: 12, hours_in_a_day: 12 }, tick_time_result.unwrap_err());
+    }
+
+    #[test]
+    fn init_with_epoch_too_far_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Real },
+                compute_events: false,
+                epoch: TickTimeEpoch { year: usize::MAX / 2, ..Default::default() },
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::TickOverflow, tick_time_result.unwrap_err());
+    }
+}
+
+#[cfg(test)]
+mod weekday_tests {
+    use crate::*;
+
+    #[test]
+    fn earthlike_weekday_should_continue_across_years() {
+        let mut tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24, month_type: EarthLikeMonthType::Real },
+                compute_events: false,
+                epoch: TickTimeEpoch { year: 2000, month: 11, day: 29, weekday: 5, ..Default::default() },
+                ..Default::default()
+            },
+        ).unwrap();
+        assert_eq!(5, tick_time.weekday());
+
+        tick_time.tick();
+        assert_eq!(6, tick_time.weekday());
+
+        tick_time.tick();
+        assert_eq!((2001, 0, 0, 0, 0, 0, 0, 0), tick_time.values());
+        assert_eq!(0, tick_time.weekday());
+    }
+
+    #[test]
+    fn custom_weekday_should_follow_week_duration() {
+        let mut tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::Custom {
+                    seconds_per_tick: 3600 * 24,
+                    hours_in_a_day: 24,
+                    months_durations: vec![2, 2],
+                    seasons_durations: vec![4],
+                    week_duration: 3,
+                },
+                compute_events: false,
+                ..Default::default()
+            },
+        ).unwrap();
+
+        for _ in 0..5 {
+            tick_time.tick();
+        }
+        assert_eq!((1, 0, 0, 0, 1, 0, 0, 0), tick_time.values());
+        assert_eq!(2, tick_time.weekday());
+    }
+
+    #[test]
+    fn should_compute_weekday_event() {
+        let mut tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24, month_type: EarthLikeMonthType::Lunar },
+                compute_events: true,
+                epoch: TickTimeEpoch { weekday: 6, ..Default::default() },
+                ..Default::default()
+            },
+        ).unwrap();
+
+        if let Some(TickTimeEvent { weekday_update: Some(weekday_event), .. }) = tick_time.tick() {
+            assert_eq!(6, weekday_event.old_value);
+            assert_eq!(0, weekday_event.new_value);
+        } else {
+            panic!("No event returned but it should");
+        }
+    }
+
+    #[test]
+    fn init_with_epoch_weekday_outside_of_week_should_return_err() {
+        let tick_time_result = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Lunar },
+                compute_events: false,
+                epoch: TickTimeEpoch { weekday: 7, ..Default::default() },
+                ..Default::default()
+            },
+        );
+        assert_eq!(TickTimeError::WeekdayOutOfRange { weekday: 7, week_duration: 7 }, tick_time_result.unwrap_err());
+    }
+}
+
+#[cfg(test)]
+mod advance_tests {
+    use crate::*;
+    use super::test_helpers::lunar_options;
+
+    #[test]
+    fn advance_should_compute_same_values_as_ticking() {
+        let mut ticked = TickTime::init(0, lunar_options(3600, false)).unwrap();
+        for _ in 0..(24 * 40 + 5) {
+            ticked.tick();
+        }
+
+        let mut advanced = TickTime::init(0, lunar_options(3600, false)).unwrap();
+        advanced.advance(24 * 40 + 5).unwrap();
+
+        assert_eq!(ticked.current_tick(), advanced.current_tick());
+        assert_eq!(ticked.values(), advanced.values());
+        assert_eq!(ticked.weekday(), advanced.weekday());
+    }
+
+    #[test]
+    fn advance_should_return_aggregated_event() {
+        let mut tick_time = TickTime::init(0, lunar_options(3600, true)).unwrap();
+
+        if let Some(TickTimeEvent {
+                        hour_update: Some(hour_event),
+                        day_update: Some(day_event),
+                        week_update: Some(week_event),
+                        month_update: Some(month_event),
+                        season_update: None,
+                        year_update: None,
+                        ..}) = tick_time.advance(24 * 40 + 5).unwrap() {
+            assert_eq!(0, hour_event.old_value);
+            assert_eq!(5, hour_event.new_value);
+            assert_eq!(0, day_event.old_value);
+            assert_eq!(10, day_event.new_value);
+            assert_eq!(0, week_event.old_value);
+            assert_eq!(5, week_event.new_value);
+            assert_eq!(0, month_event.old_value);
+            assert_eq!(1, month_event.new_value);
+        } else {
+            panic!("No event returned but it should");
+        }
+    }
+
+    #[test]
+    fn advance_by_zero_should_return_empty_event() {
+        let mut tick_time = TickTime::init(0, lunar_options(3600, true)).unwrap();
+
+        if let Some(TickTimeEvent {
+                        second_update: None,
+                        minute_update: None,
+                        hour_update: None,
+                        day_update: None,
+                        year_update: None,
+                        ..}) = tick_time.advance(0).unwrap() {
+            assert_eq!(0, tick_time.current_tick());
+        } else {
+            panic!("Advancing by 0 should not update any value");
+        }
+    }
+}
+
+#[cfg(test)]
+mod rewind_tests {
+    use crate::*;
+    use super::test_helpers::lunar_options;
+    use crate::event::TickDirection;
+
+    #[test]
+    fn rewind_should_compute_previous_values() {
+        let mut tick_time = TickTime::init(24 * 40, lunar_options(3600, false)).unwrap();
+        tick_time.rewind(24 * 10 + 1).unwrap();
+
+        assert_eq!(24 * 30 - 1, tick_time.current_tick());
+        assert_eq!((0, 0, 4, 0, 29, 23, 0, 0), tick_time.values());
+    }
+
+    #[test]
+    fn rewind_should_return_backward_event() {
+        let mut tick_time = TickTime::init(24 * 30, lunar_options(3600, true)).unwrap();
+
+        if let Some(TickTimeEvent {
+                        hour_update: Some(hour_event),
+                        day_update: Some(day_event),
+                        month_update: Some(month_event),
+                        direction,
+                        ..}) = tick_time.rewind(1).unwrap() {
+            assert_eq!(TickDirection::Backward, direction);
+            assert_eq!(0, hour_event.old_value);
+            assert_eq!(23, hour_event.new_value);
+            assert_eq!(0, day_event.old_value);
+            assert_eq!(29, day_event.new_value);
+            assert_eq!(1, month_event.old_value);
+            assert_eq!(0, month_event.new_value);
+        } else {
+            panic!("No event returned but it should");
+        }
+    }
+
+    #[test]
+    fn rewind_before_tick_0_should_return_err() {
+        let mut tick_time = TickTime::init(10, lunar_options(3600, true)).unwrap();
+
+        assert_eq!(TickTimeError::TickUnderflow { current_tick: 10, rewind: 11 }, tick_time.rewind(11).unwrap_err());
+        assert_eq!(10, tick_time.current_tick());
+    }
+
+    #[test]
+    fn set_tick_should_return_event_with_direction() {
+        let mut tick_time = TickTime::init(10, lunar_options(3600, true)).unwrap();
+
+        assert_eq!(TickDirection::Backward, tick_time.set_tick(2).unwrap().unwrap().direction);
+        assert_eq!(2, tick_time.current_tick());
+        assert_eq!(TickDirection::Forward, tick_time.set_tick(5).unwrap().unwrap().direction);
+        assert_eq!((0, 0, 0, 0, 0, 5, 0, 0), tick_time.values());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use crate::*;
+    use super::test_helpers::lunar_options;
+
+    #[test]
+    fn ticktime_should_be_restored_from_serialized_save() {
+        let mut tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::Custom {
+                    seconds_per_tick: 3600,
+                    hours_in_a_day: 12,
+                    months_durations: vec![3, 4],
+                    seasons_durations: vec![7],
+                    week_duration: 5,
+                },
+                compute_events: true,
+                epoch: TickTimeEpoch { year: 12, weekday: 3, ..Default::default() },
+                ..Default::default()
+            },
+        ).unwrap();
+        tick_time.advance(12 * 20 + 7).unwrap();
+
+        let save = serde_json::to_string(&tick_time).unwrap();
+        let restored: TickTime = serde_json::from_str(&save).unwrap();
+
+        assert_eq!(tick_time.current_tick(), restored.current_tick());
+        assert_eq!(tick_time.values(), restored.values());
+        assert_eq!(tick_time.weekday(), restored.weekday());
+    }
+
+    #[test]
+    fn options_should_be_restored_without_the_optional_fields() {
+        let save = r#"{"current_tick":25,"options":{"tick_time_type":{"EarthLike":{"seconds_per_tick":3600,"month_type":"Lunar"}},"compute_events":false}}"#;
+        let restored: TickTime = serde_json::from_str(save).unwrap();
+
+        assert_eq!((0, 0, 0, 0, 1, 1, 0, 0), restored.values());
+        assert_eq!(Some("January"), restored.month_name());
+    }
+
+    #[test]
+    fn ticktime_should_be_restored_with_its_time_scales() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        tick_time.advance(10).unwrap();
+        tick_time.set_time_scale(TimeScale::times(4)).unwrap();
+        tick_time.advance(5).unwrap();
+
+        let save = serde_json::to_string(&tick_time).unwrap();
+        let mut restored: TickTime = serde_json::from_str(&save).unwrap();
+        assert_eq!(30, restored.minute());
+        restored.tick();
+        assert_eq!(34, restored.minute());
+        restored.rewind(10).unwrap();
+        assert_eq!(6, restored.minute());
+
+        restored.pause();
+        let mut restored: TickTime = serde_json::from_str(&serde_json::to_string(&restored).unwrap()).unwrap();
+        assert!(restored.is_paused());
+        restored.resume();
+        assert_eq!(TimeScale::times(1), restored.time_scale());
+    }
+
+    #[test]
+    fn time_scales_breaking_the_date_should_not_be_deserialized() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        tick_time.advance(10).unwrap();
+        tick_time.set_time_scale(TimeScale::times(4)).unwrap();
+
+        let mut save = serde_json::to_value(&tick_time).unwrap();
+        assert_eq!(600_000, save["scale_segments"][1]["start_milliseconds"]);
+        save["scale_segments"][1]["start_milliseconds"] = serde_json::json!(900_000);
+        assert!(serde_json::from_value::<TickTime>(save.clone()).is_err());
+        save["scale_segments"][0]["start_milliseconds"] = serde_json::json!(300_000);
+        assert!(serde_json::from_value::<TickTime>(save).is_err());
+    }
+
+    #[test]
+    fn paused_ticktime_without_stopped_time_should_not_be_deserialized() {
+        let mut tick_time = TickTime::init(10, lunar_options(60, false)).unwrap();
+        tick_time.pause();
+
+        let mut save = serde_json::to_value(&tick_time).unwrap();
+        assert!(serde_json::from_value::<TickTime>(save.clone()).is_ok());
+        save["scale_segments"][1]["scale"]["numerator"] = serde_json::json!(2);
+        assert!(serde_json::from_value::<TickTime>(save).is_err());
+    }
+
+    #[test]
+    fn ticktime_should_be_restored_with_its_alarms() {
+        let mut tick_time = TickTime::init(0, lunar_options(3600, false)).unwrap();
+        let handle = tick_time.schedule_alarm(crate::alarm::AlarmSchedule::DailyAt { hour: 6, minute: 0, second: 0 }).unwrap();
+
+        let save = serde_json::to_string(&tick_time).unwrap();
+        let mut restored: TickTime = serde_json::from_str(&save).unwrap();
+        let event = restored.advance(30).unwrap().unwrap();
+        assert_eq!(vec![(handle, 6), (handle, 30)], event.alarms.iter().map(|alarm| (alarm.handle, alarm.tick)).collect::<Vec<_>>());
+    }
+
+    /// Restore a save whose only alarm has the given schedule and next date
+    fn restore_with_alarm(schedule: serde_json::Value, next_hour: usize) -> Result<TickTime, serde_json::Error> {
+        let mut tick_time = TickTime::init(0, lunar_options(3600, false)).unwrap();
+        tick_time.schedule_alarm(crate::alarm::AlarmSchedule::AtTick(1)).unwrap();
+        let mut save = serde_json::to_value(&tick_time).unwrap();
+        save["alarms"]["alarms"][0]["schedule"] = schedule;
+        save["alarms"]["alarms"][0]["next_date"]["hour"] = serde_json::json!(next_hour);
+        serde_json::from_value(save)
+    }
+
+    #[test]
+    fn repeating_alarm_with_empty_interval_should_not_be_deserialized() {
+        let interval = |hours: usize| serde_json::json!({ "years": 0, "months": 0, "weeks": 0, "days": 0, "hours": hours, "minutes": 0, "seconds": 0 });
+        assert!(restore_with_alarm(serde_json::json!({ "Every": interval(1) }), 1).is_ok());
+
+        let error = restore_with_alarm(serde_json::json!({ "Every": interval(0) }), 1).unwrap_err();
+        assert_eq!(TickTimeError::ZeroAlarmInterval.to_string(), error.to_string());
+    }
+
+    #[test]
+    fn daily_alarm_with_invalid_time_should_not_be_deserialized() {
+        let daily_at = |hour: usize| serde_json::json!({ "DailyAt": { "hour": hour, "minute": 0, "second": 0 } });
+        assert!(restore_with_alarm(daily_at(6), 6).is_ok());
+
+        let error = restore_with_alarm(daily_at(24), 6).unwrap_err();
+        assert_eq!(TickTimeError::HourOutOfRange { hour: 24, hours_in_a_day: 24 }.to_string(), error.to_string());
+        let error = restore_with_alarm(daily_at(6), 30).unwrap_err();
+        assert_eq!(TickTimeError::HourOutOfRange { hour: 30, hours_in_a_day: 24 }.to_string(), error.to_string());
+    }
+
+    #[test]
+    fn date_alarm_with_invalid_date_should_not_be_deserialized() {
+        let at_date = |month: usize| serde_json::json!({ "AtDate": { "year": 0, "month": month, "day": 0, "hour": 0, "minute": 0, "second": 0 } });
+        assert!(restore_with_alarm(at_date(11), 0).is_ok());
+
+        let error = restore_with_alarm(at_date(12), 0).unwrap_err();
+        assert_eq!(TickTimeError::MonthOutOfRange { month: 12, months_in_year: 12 }.to_string(), error.to_string());
+    }
+
+    #[test]
+    fn ticktime_with_invalid_options_should_not_be_deserialized() {
+        let save = r#"{
+            "current_tick": 10,
+            "options": {
+                "tick_time_type": { "EarthLike": { "seconds_per_tick": 0, "month_type": "Real" } },
+                "compute_events": false,
+                "epoch": { "year": 0, "month": 0, "day": 0, "hour": 0, "minute": 0, "second": 0, "weekday": 0 }
+            }
+        }"#;
+
+        assert!(serde_json::from_str::<TickTime>(save).is_err());
+    }
+
+    #[test]
+    fn ticktime_saved_without_tick_rate_should_be_deserialized() {
+        let save = r#"{
+            "current_tick": 10,
+            "options": {
+                "tick_time_type": { "EarthLike": { "seconds_per_tick": 60, "month_type": "Real" } },
+                "compute_events": false,
+                "epoch": { "year": 0, "month": 0, "day": 0, "hour": 0, "minute": 0, "second": 0, "weekday": 0 }
+            }
+        }"#;
+
+        let tick_time = serde_json::from_str::<TickTime>(save).unwrap();
+        assert_eq!(10, tick_time.minute());
+    }
+
+    #[test]
+    fn event_should_be_serialized() {
+        let mut tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Lunar },
+                compute_events: true,
+                ..Default::default()
+            },
+        ).unwrap();
+        let event = tick_time.tick().unwrap();
+
+        let json = serde_json::to_string(&event).unwrap();
+        let restored: TickTimeEvent = serde_json::from_str(&json).unwrap();
+
+        let second_update = restored.second_update.unwrap();
+        assert_eq!(0, second_update.old_value);
+        assert_eq!(1, second_update.new_value);
+        assert!(restored.minute_update.is_none());
+    }
+}
+
+#[cfg(test)]
+mod names_tests {
+    use crate::*;
+    use super::test_helpers::named_custom_tick_time;
+
+    fn monsoon_names() -> CalendarNames {
+        CalendarNames {
+            months: vec!["Rain".to_string(), "Dust".to_string()],
+            seasons: vec!["Wet".to_string(), "Dry".to_string(), "Storm".to_string()],
+            weekdays: vec![],
+        }
+    }
+
+    #[test]
+    fn earthlike_should_use_english_names_by_default() {
+        let tick_time = TickTime::init(
+            96,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24, month_type: EarthLikeMonthType::Real },
+                compute_events: false,
+                epoch: TickTimeEpoch { weekday: 2, ..Default::default() },
+                ..Default::default()
+            },
+        ).unwrap();
+
+        assert_eq!(Some("April"), tick_time.month_name());
+        assert_eq!(Some("Spring"), tick_time.season_name());
+        assert_eq!(Some("Monday"), tick_time.weekday_name());
+    }
+
+    #[test]
+    fn earthlike_should_use_configured_names() {
+        let tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike {
+                    seconds_per_tick: 1,
+                    month_type: EarthLikeMonthType::Lunar,
+                },
+                compute_events: false,
+                names: Some(CalendarNames {
+                    weekdays: vec!["Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi", "Dimanche"]
+                        .into_iter().map(String::from).collect(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ).unwrap();
+
+        assert_eq!(Some("January"), tick_time.month_name());
+        assert_eq!(Some("Lundi"), tick_time.weekday_name());
+    }
+
+    #[test]
+    fn custom_should_use_configured_names() {
+        let tick_time = named_custom_tick_time(9, Some(monsoon_names())).unwrap();
+
+        assert_eq!(Some("Dust"), tick_time.month_name());
+        assert_eq!(Some("Storm"), tick_time.season_name());
+        assert_eq!(None, tick_time.weekday_name());
+    }
+
+    #[test]
+    fn custom_without_names_should_not_return_names() {
+        let tick_time = named_custom_tick_time(9, None).unwrap();
+
+        assert_eq!(None, tick_time.month_name());
+        assert_eq!(None, tick_time.season_name());
+        assert_eq!(None, tick_time.weekday_name());
+    }
+
+    #[test]
+    fn display_should_use_names() {
+        let tick_time = named_custom_tick_time(9, Some(monsoon_names())).unwrap();
+
+        assert_eq!(
+            "Tick time: [ Current tick: 9, Year: 0, Season: Storm, Week: 3 Month: Dust, Day: 3, Weekday: 0, Hour: 0, Minute: 0, Second: 0]",
+            tick_time.to_string()
+        );
+    }
+
+    #[test]
+    fn init_with_wrong_names_count_should_return_err() {
+        let names = CalendarNames { seasons: vec!["Wet".to_string()], ..monsoon_names() };
+
+        assert_eq!(
+            TickTimeError::SeasonNamesCountMismatch { expected: 3, actual: 1 },
+            named_custom_tick_time(0, Some(names)).unwrap_err()
+        );
+    }
+}
+
+#[cfg(test)]
+mod format_tests {
+    use crate::*;
+    use super::test_helpers::real_tick_time_at;
+
+    #[test]
+    fn format_should_write_numeric_values_with_zero_padding() {
+        let tick_time = real_tick_time_at(1, TickTimeEpoch { year: 1203, month: 2, day: 4, hour: 6, minute: 7, second: 8, weekday: 3 });
+
+        assert_eq!("1203-02-04 06:07:08", tick_time.format("%Y-%m-%d %H:%M:%S"));
+        assert_eq!("Week 09, weekday 3, season 0", tick_time.format("Week %W, weekday %u, season %Q"));
+    }
+
+    #[test]
+    fn format_should_write_names() {
+        let tick_time = real_tick_time_at(1, TickTimeEpoch { year: 1203, month: 2, day: 4, weekday: 3, ..Default::default() });
+
+        assert_eq!("Thursday 4 March 1203, Winter", tick_time.format("%A %-d %B %Y, %q"));
+    }
+
+    #[test]
+    fn format_should_handle_padding_options() {
+        let tick_time = real_tick_time_at(1, TickTimeEpoch { year: 12, month: 2, day: 4, hour: 6, ..Default::default() });
+
+        assert_eq!("0012|  12|12", tick_time.format("%Y|%_Y|%-Y"));
+        assert_eq!("02|04| 6|6", tick_time.format("%m|%d|%_H|%-H"));
+    }
+
+    #[test]
+    fn format_should_write_12_hours_clock() {
+        assert_eq!("12:00 AM", real_tick_time_at(1, TickTimeEpoch { hour: 0, ..Default::default() }).format("%I:%M %p"));
+        assert_eq!("11:00 AM", real_tick_time_at(1, TickTimeEpoch { hour: 11, ..Default::default() }).format("%I:%M %p"));
+        assert_eq!("12:00 PM", real_tick_time_at(1, TickTimeEpoch { hour: 12, ..Default::default() }).format("%I:%M %p"));
+        assert_eq!("01:00 PM", real_tick_time_at(1, TickTimeEpoch { hour: 13, ..Default::default() }).format("%I:%M %p"));
+    }
+
+    #[test]
+    fn format_should_fall_back_to_numbers_without_names() {
+        let tick_time = TickTime::init(
+            0,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::Custom {
+                    seconds_per_tick: 1,
+                    hours_in_a_day: 10,
+                    months_durations: vec![5, 5],
+                    seasons_durations: vec![10],
+                    week_duration: 5,
+                },
+                compute_events: false,
+                epoch: TickTimeEpoch { month: 1, hour: 7, weekday: 2, ..Default::default() },
+                ..Default::default()
+            },
+        ).unwrap();
+
+        assert_eq!("01 2 0 02 PM", tick_time.format("%B %A %q %I %p"));
+    }
+
+    #[test]
+    fn format_should_keep_unknown_tokens_and_escaped_percent() {
+        let tick_time = real_tick_time_at(1, Default::default());
+
+        assert_eq!("100% %K %-K %", tick_time.format("100%% %K %-K %"));
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use crate::*;
+    use super::test_helpers::lunar_options_at;
+
+    #[test]
+    fn parse_should_return_tick_of_date() {
+        let tick = TickTime::parse(
+            "Year 3, Month 5, Day 12 08:00",
+            "Year %-Y, Month %-m, Day %-d %H:%M",
+            &lunar_options_at(3600, Default::default()),
+        ).unwrap();
+
+        assert_eq!((3 * 360 + 5 * 30 + 12) * 24 + 8, tick);
+    }
+
+    #[test]
+    fn parse_should_be_the_inverse_of_format() {
+        let options = TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Real },
+            compute_events: false,
+            epoch: TickTimeEpoch { year: 1999, month: 11, day: 3, weekday: 4, ..Default::default() },
+            ..Default::default()
+        };
+        let pattern = "%A %-d %B %Y (%q, week %W), %I:%M:%S %p";
+        let tick_time = TickTime::init(3600 * 24 * 92 + 3600 * 13 + 61, options.clone()).unwrap();
+
+        let formatted = tick_time.format(pattern);
+        assert_eq!("Saturday 4 March 2000 (Winter, week 09), 01:01:01 PM", formatted);
+        assert_eq!(tick_time.current_tick(), TickTime::parse(&formatted, pattern, &options).unwrap());
+    }
+
+    #[test]
+    fn parse_should_read_values_without_separator() {
+        let tick = TickTime::parse("00010203", "%Y%m%d", &lunar_options_at(3600 * 24, Default::default())).unwrap();
+
+        assert_eq!(360 + 2 * 30 + 3, tick);
+    }
+
+    #[test]
+    fn parse_should_return_earliest_tick_at_or_after_date() {
+        let options = lunar_options_at(3600, Default::default());
+
+        assert_eq!(1, TickTime::parse("00:30", "%H:%M", &options).unwrap());
+        assert_eq!(1, TickTime::parse("01:00", "%H:%M", &options).unwrap());
+    }
+
+    #[test]
+    fn parse_day_outside_of_month_should_return_err() {
+        assert_eq!(
+            TickTimeError::DayOutOfRange { day: 30, days_in_month: 30 },
+            TickTime::parse("0-4-30", "%-Y-%-m-%-d", &lunar_options_at(1, Default::default())).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_date_before_epoch_should_return_err() {
+        assert_eq!(
+            TickTimeError::DateBeforeEpoch,
+            TickTime::parse("2-4-3", "%-Y-%-m-%-d", &lunar_options_at(1, TickTimeEpoch { year: 3, ..Default::default() })).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_year_too_far_should_return_err() {
+        let real_options = TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Real },
+            compute_events: false,
+            ..Default::default()
+        };
+
+        assert_eq!(TickTimeError::TickOverflow, TickTime::parse("99999999999999999", "%Y", &real_options).unwrap_err());
+        assert_eq!(TickTimeError::TickOverflow, TickTime::parse("99999999999999999", "%Y", &lunar_options_at(1, Default::default())).unwrap_err());
+    }
+
+    #[test]
+    fn parse_input_not_matching_pattern_should_return_err() {
+        let options = lunar_options_at(1, Default::default());
+
+        assert_eq!(TickTimeError::ParseMismatch { position: 6 }, TickTime::parse("Year 1/2", "Year %-Y, %-m", &options).unwrap_err());
+        assert_eq!(TickTimeError::ParseMismatch { position: 5 }, TickTime::parse("Year x", "Year %-Y", &options).unwrap_err());
+        assert_eq!(TickTimeError::ParseMismatch { position: 6 }, TickTime::parse("Year 1 and more", "Year %-Y", &options).unwrap_err());
+    }
+
+    #[test]
+    fn parse_inconsistent_weekday_should_return_err() {
+        assert_eq!(
+            TickTimeError::InconsistentDate { position: 0 },
+            TickTime::parse("Tuesday 0-0-0", "%A %-Y-%-m-%-d", &lunar_options_at(1, Default::default())).unwrap_err()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tick_for_tests {
+    use crate::*;
+    use super::test_helpers::{real_tick_time_at, week_year_custom_tick_time};
+    use crate::date::GameDateTime;
+
+    #[test]
+    fn tick_for_should_respect_real_leap_years() {
+        let march_first = |year| GameDateTime { year, month: 2, ..Default::default() };
+        let (from_2000, from_2100) = (TickTimeEpoch { year: 2000, ..Default::default() }, TickTimeEpoch { year: 2100, ..Default::default() });
+
+        assert_eq!(Ok(60), real_tick_time_at(3600 * 24, from_2000.clone()).tick_for(&march_first(2000)));
+        assert_eq!(Ok(59), real_tick_time_at(3600 * 24, from_2100).tick_for(&march_first(2100)));
+        assert_eq!(Ok(366), real_tick_time_at(3600 * 24, from_2000).tick_for(&GameDateTime { year: 2001, ..Default::default() }));
+    }
+
+    #[test]
+    fn tick_for_should_respect_custom_months_durations() {
+        let date = GameDateTime { year: 1, month: 1, day: 2, hour: 5, ..Default::default() };
+
+        assert_eq!(Ok((7 + 3 + 2) * 12 + 5), week_year_custom_tick_time(3600).tick_for(&date));
+    }
+
+    #[test]
+    fn tick_for_should_return_earliest_tick_at_or_after_date() {
+        let date = GameDateTime { year: 1, month: 1, day: 2, hour: 5, ..Default::default() };
+
+        assert_eq!(Ok(75), week_year_custom_tick_time(7200).tick_for(&date));
+    }
+
+    #[test]
+    fn tick_for_should_be_the_inverse_of_tick_values() {
+        let date = GameDateTime { year: 2003, month: 8, day: 17, ..Default::default() };
+        let tick_time = real_tick_time_at(3600 * 24, TickTimeEpoch { year: 2000, ..Default::default() });
+        let tick = tick_time.tick_for(&date).unwrap();
+
+        let tick_time = TickTime::init(tick, tick_time.options.clone()).unwrap();
+        assert_eq!((2003, 8, 17), (tick_time.year(), tick_time.month(), tick_time.day()));
+    }
+
+    #[test]
+    fn tick_for_invalid_date_should_return_err() {
+        let tick_time = real_tick_time_at(3600 * 24, TickTimeEpoch { year: 2000, ..Default::default() });
+
+        assert_eq!(
+            Err(TickTimeError::DayOutOfRange { day: 28, days_in_month: 28 }),
+            tick_time.tick_for(&GameDateTime { year: 2001, month: 1, day: 28, ..Default::default() })
+        );
+        assert_eq!(
+            Err(TickTimeError::DateBeforeEpoch),
+            tick_time.tick_for(&GameDateTime { year: 1999, ..Default::default() })
+        );
+    }
+
+    #[test]
+    fn tick_for_date_too_far_should_return_err() {
+        let tick_time = real_tick_time_at(3600 * 24, TickTimeEpoch { year: 2000, ..Default::default() });
+
+        assert_eq!(Err(TickTimeError::TickOverflow), tick_time.tick_for(&GameDateTime { year: usize::MAX / 2, ..Default::default() }));
+        assert_eq!(Err(TickTimeError::TickOverflow), tick_time.tick_for(&GameDateTime { year: 10usize.pow(15), ..Default::default() }));
+    }
+}
+
+#[cfg(test)]
+mod game_date_time_tests {
+    use crate::*;
+    use crate::date::GameDateTime;
+    use std::collections::HashMap;
+
+    #[test]
+    fn now_should_return_computed_date() {
+        let tick_time = TickTime::init(
+            3600 * 24 * 45 + 3600 * 5 + 62,
+            TickTimeOptions {
+                tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Lunar },
+                compute_events: false,
+                epoch: TickTimeEpoch { year: 7, ..Default::default() },
+                ..Default::default()
+            },
+        ).unwrap();
+
+        let now = tick_time.now();
+        assert_eq!(GameDateTime { year: 7, month: 1, day: 15, hour: 5, minute: 1, second: 2, ..Default::default() }, now);
+        assert_eq!((0, 6, 3), (now.season, now.week, now.weekday));
+    }
+
+    #[test]
+    fn dates_should_be_ordered_chronologically() {
+        let date = GameDateTime { year: 3, month: 5, day: 12, hour: 8, ..Default::default() };
+
+        assert!(date < GameDateTime { year: 4, ..Default::default() });
+        assert!(date < GameDateTime { day: 13, ..date });
+        assert!(date > GameDateTime { month: 4, day: 29, hour: 23, ..date });
+        assert!(date > GameDateTime { hour: 7, minute: 59, second: 59, ..date });
+    }
+
+    #[test]
+    fn dates_should_be_usable_as_map_keys() {
+        let mut deadlines = HashMap::new();
+        deadlines.insert(GameDateTime { year: 3, ..Default::default() }, "harvest");
+
+        assert_eq!(Some(&"harvest"), deadlines.get(&GameDateTime { year: 3, ..Default::default() }));
+    }
+
+    #[test]
+    fn dates_should_be_compared_without_their_computed_values() {
+        let tick_time = TickTime::init(3600 * 24 * 45, TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Lunar },
+            compute_events: false,
+            ..Default::default()
+        }).unwrap();
+        let date = GameDateTime { month: 1, day: 15, ..Default::default() };
+        let mut deadlines = HashMap::new();
+        deadlines.insert(date, "harvest");
+
+        assert_eq!(date, tick_time.now());
+        assert_eq!(Some(&"harvest"), deadlines.get(&tick_time.now()));
+        assert!(GameDateTime { season: 3, ..date } <= tick_time.now());
+    }
+
+    #[test]
+    fn date_should_be_displayed() {
+        let date = GameDateTime { year: 3, month: 5, day: 12, hour: 8, minute: 0, second: 9, ..Default::default() };
+
+        assert_eq!("Year 3, Month 5, Day 12, 08:00:09", date.to_string());
+    }
+}
+
+#[cfg(test)]
+mod duration_tests {
+    use crate::*;
+    use super::test_helpers::{custom_options, date, real_options_at};
+    use crate::date::GameDateTime;
+    use crate::duration::GameDuration;
+
+    #[test]
+    fn to_ticks_should_honour_seconds_per_tick_and_hours_in_a_day() {
+        assert_eq!(Ok(24 * 40), GameDuration::days(40).to_ticks(&real_options_at(3600, Default::default())));
+        assert_eq!(Ok(12 * 3 + 2), (GameDuration::days(3) + GameDuration::hours(2)).to_ticks(&custom_options()));
+        assert_eq!(Ok(12 * 5), GameDuration::weeks(1).to_ticks(&custom_options()));
+        assert_eq!(Ok(1), GameDuration::minutes(1).to_ticks(&custom_options()));
+    }
+
+    #[test]
+    fn to_ticks_with_months_or_years_should_return_err() {
+        assert_eq!(Err(TickTimeError::CalendarDependentDuration), GameDuration::months(1).to_ticks(&custom_options()));
+        assert_eq!(Err(TickTimeError::CalendarDependentDuration), GameDuration::years(1).to_ticks(&custom_options()));
+    }
+
+    #[test]
+    fn add_duration_should_clamp_day_to_the_end_of_the_month() {
+        let tick_time = TickTime::init(0, real_options_at(1, Default::default())).unwrap();
+        let add = |date: GameDateTime, duration: GameDuration| {
+            tick_time.add_duration(&date, &duration).map(|date| (date.year, date.month, date.day))
+        };
+
+        assert_eq!(Ok((2001, 1, 27)), add(date(2001, 0, 30), GameDuration::months(1)));
+        assert_eq!(Ok((2000, 1, 28)), add(date(2000, 0, 30), GameDuration::months(1)));
+        assert_eq!(Ok((2000, 3, 29)), add(date(2000, 2, 30), GameDuration::months(1)));
+        assert_eq!(Ok((2001, 1, 27)), add(date(2000, 1, 28), GameDuration::years(1)));
+        assert_eq!(Ok((2002, 0, 14)), add(date(2000, 11, 14), GameDuration::months(13)));
+    }
+
+    #[test]
+    fn add_duration_should_add_fixed_units_after_calendar_units() {
+        let tick_time = TickTime::init(0, real_options_at(1, Default::default())).unwrap();
+        let duration = GameDuration::months(1) + GameDuration::days(1) + GameDuration::hours(25);
+
+        assert_eq!(
+            Ok(GameDateTime { year: 2001, month: 2, day: 1, hour: 1, season: 0, week: 8, weekday: 6, ..Default::default() }),
+            tick_time.add_duration(&date(2001, 0, 30), &duration)
+        );
+    }
+
+    #[test]
+    fn add_duration_to_invalid_date_should_return_err() {
+        let tick_time = TickTime::init(0, real_options_at(1, Default::default())).unwrap();
+
+        assert_eq!(
+            Err(TickTimeError::DayOutOfRange { day: 31, days_in_month: 31 }),
+            tick_time.add_duration(&date(2001, 0, 31), &GameDuration::days(1))
+        );
+    }
+
+    #[test]
+    fn ticks_in_should_follow_the_calendar_from_current_date() {
+        let tick_time = TickTime::init(0, real_options_at(3600 * 24, TickTimeEpoch { year: 2000, ..Default::default() })).unwrap();
+        assert_eq!(31, tick_time.ticks_in(&GameDuration::months(1)).unwrap());
+        assert_eq!(366, tick_time.ticks_in(&GameDuration::years(1)).unwrap());
+
+        let tick_time = TickTime::init(31, tick_time.options.clone()).unwrap();
+        assert_eq!(29, tick_time.ticks_in(&GameDuration::months(1)).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod between_tests {
+    use crate::*;
+    use super::test_helpers::{custom_options, date, real_options_at};
+    use crate::date::GameDateTime;
+    use crate::duration::GameDuration;
+
+    #[test]
+    fn duration_between_should_break_down_in_calendar_units() {
+        let tick_time = TickTime::init(0, real_options_at(3600, TickTimeEpoch { year: 2000, ..Default::default() })).unwrap();
+        let from = date(2000, 0, 10);
+        let to = GameDateTime { hour: 5, minute: 6, second: 7, ..date(2002, 3, 14) };
+        let expected = GameDuration { years: 2, months: 3, days: 4, hours: 5, minutes: 6, seconds: 7, ..Default::default() };
+        assert_eq!(Ok(expected), tick_time.duration_between(&from, &to));
+        assert_eq!(Ok(expected), tick_time.duration_between(&to, &from));
+        assert_eq!(Ok(GameDuration::default()), tick_time.duration_between(&from, &from));
+    }
+
+    #[test]
+    fn duration_between_should_follow_month_lengths() {
+        let tick_time = TickTime::init(0, real_options_at(3600, TickTimeEpoch { year: 2000, ..Default::default() })).unwrap();
+        // January 31st to March 1st of a leap year: a month to February 29th, then a day
+        let expected = GameDuration { months: 1, days: 1, ..Default::default() };
+        assert_eq!(Ok(expected), tick_time.duration_between(&date(2000, 0, 30), &date(2000, 2, 0)));
+        // A month from January 31st ends on the last day of February
+        assert_eq!(Ok(GameDuration::months(1)), tick_time.duration_between(&date(2001, 0, 30), &date(2001, 1, 27)));
+        // A year from February 29th ends on February 28th
+        assert_eq!(Ok(GameDuration::years(1)), tick_time.duration_between(&date(2000, 1, 28), &date(2001, 1, 27)));
+        // Not a full year before the day and hour are reached
+        let expected = GameDuration { months: 11, days: 30, hours: 23, ..Default::default() };
+        let to = GameDateTime { hour: 23, ..date(2000, 11, 30) };
+        assert_eq!(Ok(expected), tick_time.duration_between(&date(2000, 0, 0), &to));
+    }
+
+    #[test]
+    fn duration_between_should_follow_custom_calendar() {
+        let tick_time = TickTime::init(0, custom_options()).unwrap();
+        // A month after the 5th day of the second month would be the 5th day of the next year
+        let expected = GameDuration { years: 1, days: 2, hours: 11, ..Default::default() };
+        let to = GameDateTime { hour: 11, ..date(2, 0, 1) };
+        assert_eq!(Ok(expected), tick_time.duration_between(&date(0, 1, 4), &to));
+        // The 10th day of a month is clamped to the last day of the 5 days month
+        assert_eq!(Ok(GameDuration::months(1)), tick_time.duration_between(&date(0, 0, 9), &date(0, 1, 4)));
+    }
+
+    #[test]
+    fn duration_between_should_reject_invalid_dates() {
+        let tick_time = TickTime::init(0, custom_options()).unwrap();
+        assert_eq!(
+            Err(TickTimeError::DayOutOfRange { day: 5, days_in_month: 5 }),
+            tick_time.duration_between(&date(0, 0, 0), &date(0, 1, 5))
+        );
+    }
+
+    #[test]
+    fn between_should_compare_ticks_and_tick_times() {
+        let a = TickTime::init(24 * 31 + 2, real_options_at(3600, TickTimeEpoch { year: 2000, ..Default::default() })).unwrap();
+        let b = TickTime::init(24 * (366 + 31 + 28 + 3), real_options_at(3600, TickTimeEpoch { year: 2000, ..Default::default() })).unwrap();
+        let expected = GameDuration { years: 1, months: 1, days: 2, hours: 22, ..Default::default() };
+        assert_eq!(expected, TickTime::between(&a, &b));
+        assert_eq!(expected, TickTime::between(&b, &a));
+        assert_eq!(Ok(expected), a.duration_between_ticks(a.current_tick, b.current_tick));
+    }
+
+    #[test]
+    fn between_should_follow_the_time_scale_of_each_tick_time() {
+        let options = TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Real },
+            compute_events: false,
+            ..Default::default()
+        };
+        let mut a = TickTime::init(0, options.clone()).unwrap();
+        let mut b = TickTime::init(0, options).unwrap();
+        b.set_time_scale(TimeScale::times(3600)).unwrap();
+        for _ in 0..10 {
+            a.tick();
+            b.tick();
+        }
+
+        assert_eq!((0, 0, 10), (a.hour(), a.minute(), a.second()));
+        assert_eq!((10, 0, 0), (b.hour(), b.minute(), b.second()));
+        let expected = GameDuration { hours: 9, minutes: 59, seconds: 50, ..Default::default() };
+        assert_eq!(expected, TickTime::between(&a, &b));
+        assert_eq!(expected, TickTime::between(&b, &a));
+    }
+}
+
+#[cfg(test)]
+mod tick_rate_tests {
+    use crate::*;
+    use super::test_helpers::real_options_with_rate;
+    use crate::date::GameDateTime;
+    use crate::duration::GameDuration;
+
+    #[test]
+    fn tick_rate_should_replace_seconds_per_tick() {
+        let tick_time = TickTime::init(61, real_options_with_rate(Some(TickRate::milliseconds_per_tick(500)))).unwrap();
+        assert_eq!((0, 30, 500), (tick_time.minute(), tick_time.second(), tick_time.millisecond()));
+
+        let tick_time = TickTime::init(2, real_options_with_rate(None)).unwrap();
+        assert_eq!((2, 0, 0), (tick_time.hour(), tick_time.second(), tick_time.millisecond()));
+    }
+
+    #[test]
+    fn default_options_should_count_one_second_per_tick() {
+        let tick_time = TickTime::init(61, Default::default()).unwrap();
+        assert_eq!((1, 1, 0), (tick_time.minute(), tick_time.second(), tick_time.millisecond()));
+    }
+
+    #[test]
+    fn far_ticks_should_keep_their_milliseconds() {
+        let tick = usize::MAX / 500;
+        let tick_time = TickTime::init(tick, Default::default()).unwrap();
+        assert_eq!(tick as u128 * 1000, tick_time.elapsed_milliseconds());
+        assert_eq!(tick % 60, tick_time.second());
+    }
+
+    #[test]
+    fn ticks_too_far_to_be_counted_should_return_err() {
+        let options = real_options_with_rate(Some(TickRate::milliseconds_per_tick(2000)));
+        assert_eq!(TickTimeError::TickOverflow, TickTime::init(usize::MAX, options.clone()).unwrap_err());
+
+        let mut tick_time = TickTime::init(usize::MAX / 2, options.clone()).unwrap();
+        assert_eq!(TickTimeError::TickOverflow, tick_time.advance(1).unwrap_err());
+        assert_eq!(usize::MAX / 2, tick_time.current_tick());
+
+        let mut tick_time = TickTime::init(0, options).unwrap();
+        tick_time.set_time_scale(TimeScale::times(usize::MAX)).unwrap();
+        assert_eq!(TickTimeError::TickOverflow, tick_time.set_tick(usize::MAX).unwrap_err());
+        assert_eq!(0, tick_time.current_tick());
+    }
+
+    #[test]
+    fn tick_rate_should_support_rational_durations() {
+        // 60 ticks per second
+        let mut tick_time = TickTime::init(0, real_options_with_rate(Some(TickRate::seconds_per_ticks(1, 60)))).unwrap();
+        tick_time.advance(59).unwrap();
+        assert_eq!((0, 983), (tick_time.second(), tick_time.millisecond()));
+        tick_time.tick();
+        assert_eq!((1, 0), (tick_time.second(), tick_time.millisecond()));
+        tick_time.advance(60 * 3600 * 24).unwrap();
+        assert_eq!((1, 0, 0, 1), (tick_time.day(), tick_time.hour(), tick_time.minute(), tick_time.second()));
+
+        // 10 seconds every 3 ticks
+        let tick_time = TickTime::init(4, real_options_with_rate(Some(TickRate::seconds_per_ticks(10, 3)))).unwrap();
+        assert_eq!((13, 333), (tick_time.second(), tick_time.millisecond()));
+    }
+
+    #[test]
+    fn tick_rate_should_convert_dates_and_durations_to_ticks() {
+        let tick_time = TickTime::init(0, real_options_with_rate(Some(TickRate::seconds_per_ticks(1, 60)))).unwrap();
+        let date = GameDateTime { minute: 1, second: 2, ..Default::default() };
+        assert_eq!(Ok(62 * 60), tick_time.tick_for(&date));
+        assert_eq!(Ok(90), GameDuration::seconds(90).to_ticks(&real_options_with_rate(Some(TickRate::milliseconds_per_tick(1000)))));
+        assert_eq!(Ok(34), GameDuration::seconds(100).to_ticks(&real_options_with_rate(Some(TickRate::seconds_per_ticks(3, 1)))));
+    }
+
+    #[test]
+    fn tick_rate_should_not_be_zero() {
+        let zero_milliseconds = Some(TickRate { milliseconds: 0, ticks: 1 });
+        assert_eq!(TickTimeError::ZeroTickRate, TickTime::init(0, real_options_with_rate(zero_milliseconds)).unwrap_err());
+        assert_eq!(TickTimeError::ZeroTickRate, TickTime::init(0, real_options_with_rate(Some(TickRate::seconds_per_ticks(1, 0)))).unwrap_err());
+
+        // seconds_per_tick is ignored when a tick rate is set
+        let mut options = real_options_with_rate(Some(TickRate::milliseconds_per_tick(1)));
+        options.tick_time_type = TickTimeType::EarthLike { seconds_per_tick: 0, month_type: EarthLikeMonthType::Lunar };
+        assert!(TickTime::init(0, options).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod time_scale_tests {
+    use crate::*;
+    use super::test_helpers::lunar_options;
+    use crate::date::GameDateTime;
+    use crate::duration::GameDuration;
+
+    #[test]
+    fn time_scale_should_keep_elapsed_time_continuous() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        tick_time.advance(10).unwrap();
+        assert_eq!(Ok(()), tick_time.set_time_scale(TimeScale::times(5)));
+        assert_eq!(10, tick_time.minute());
+        tick_time.advance(2).unwrap();
+        assert_eq!((12, 20), (tick_time.current_tick(), tick_time.minute()));
+        assert_eq!(Ok(()), tick_time.set_time_scale(TimeScale { numerator: 1, denominator: 2 }));
+        tick_time.advance(4).unwrap();
+        assert_eq!((16, 22), (tick_time.current_tick(), tick_time.minute()));
+        assert_eq!(TimeScale { numerator: 1, denominator: 2 }, tick_time.time_scale());
+    }
+
+    #[test]
+    fn time_scale_should_stop_the_time_when_zero() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        tick_time.advance(3).unwrap();
+        tick_time.set_time_scale(TimeScale::times(0)).unwrap();
+        tick_time.advance(100).unwrap();
+        assert_eq!((103, 3), (tick_time.current_tick(), tick_time.minute()));
+        tick_time.set_time_scale(Default::default()).unwrap();
+        tick_time.tick();
+        assert_eq!(4, tick_time.minute());
+    }
+
+    #[test]
+    fn past_ticks_should_keep_their_scale() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        tick_time.advance(10).unwrap();
+        tick_time.set_time_scale(TimeScale::times(2)).unwrap();
+        tick_time.advance(10).unwrap();
+        assert_eq!(30, tick_time.minute());
+        tick_time.rewind(15).unwrap();
+        assert_eq!((5, 5), (tick_time.current_tick(), tick_time.minute()));
+        assert_eq!(TimeScale::times(1), tick_time.time_scale());
+
+        // Setting a scale in the past drops the following scales
+        tick_time.set_time_scale(TimeScale::times(3)).unwrap();
+        tick_time.advance(10).unwrap();
+        assert_eq!(35, tick_time.minute());
+    }
+
+    #[test]
+    fn tick_for_should_follow_time_scales() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        tick_time.advance(10).unwrap();
+        tick_time.set_time_scale(TimeScale::times(0)).unwrap();
+        tick_time.advance(10).unwrap();
+        tick_time.set_time_scale(TimeScale::times(3)).unwrap();
+        tick_time.advance(10).unwrap();
+
+        let at = |minute| GameDateTime { minute, ..Default::default() };
+        assert_eq!(Ok(5), tick_time.tick_for(&at(5)));
+        assert_eq!(Ok(10), tick_time.tick_for(&at(10)));
+        assert_eq!(Ok(21), tick_time.tick_for(&at(11)));
+        assert_eq!(Ok(34), tick_time.tick_for(&at(50)));
+        assert_eq!(Ok(20), tick_time.ticks_in(&GameDuration::hours(1)));
+
+        tick_time.set_time_scale(TimeScale::times(0)).unwrap();
+        assert_eq!(Ok(30), tick_time.tick_for(&at(40)));
+        assert_eq!(Err(TickTimeError::DateUnreachable), tick_time.tick_for(&at(41)));
+        assert_eq!(Err(TickTimeError::DateUnreachable), tick_time.ticks_in(&GameDuration::minutes(1)));
+        assert_eq!(Ok(0), tick_time.ticks_in(&GameDuration::default()));
+    }
+
+    #[test]
+    fn same_scales_should_not_add_segments() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        for _ in 0..10 {
+            tick_time.tick();
+            tick_time.set_time_scale(TimeScale::times(1)).unwrap();
+        }
+        assert_eq!(1, tick_time.scale_segments.len());
+
+        tick_time.set_time_scale(TimeScale::times(2)).unwrap();
+        tick_time.set_time_scale(TimeScale::times(3)).unwrap();
+        tick_time.pause();
+        tick_time.resume();
+        tick_time.advance(5).unwrap();
+        tick_time.set_time_scale(TimeScale::times(3)).unwrap();
+        assert_eq!(2, tick_time.scale_segments.len());
+        assert_eq!(25, tick_time.minute());
+    }
+
+    #[test]
+    fn time_scale_denominator_should_not_be_zero() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        assert_eq!(
+            Err(TickTimeError::ZeroTimeScaleDenominator),
+            tick_time.set_time_scale(TimeScale { numerator: 1, denominator: 0 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod pause_tests {
+    use crate::*;
+    use super::test_helpers::lunar_options;
+    use crate::event::TickDirection;
+
+    #[test]
+    fn paused_time_should_keep_counting_ticks_only() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, true)).unwrap();
+        tick_time.advance(5).unwrap();
+        assert!(!tick_time.is_paused());
+
+        tick_time.pause();
+        assert!(tick_time.is_paused());
+        assert!(tick_time.tick().is_none());
+        assert!(tick_time.advance(100).unwrap().is_none());
+        assert_eq!((106, 5), (tick_time.current_tick(), tick_time.minute()));
+        assert_eq!(5 * 60 * 1000, tick_time.elapsed_milliseconds());
+
+        tick_time.resume();
+        assert!(!tick_time.is_paused());
+        let event = tick_time.tick().unwrap();
+        assert_eq!(5, event.minute_update.unwrap().old_value);
+        assert_eq!((107, 6), (tick_time.current_tick(), tick_time.minute()));
+        assert_eq!(6 * 60 * 1000, tick_time.elapsed_milliseconds());
+    }
+
+    #[test]
+    fn resume_should_restore_the_time_scale() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, false)).unwrap();
+        tick_time.set_time_scale(TimeScale::times(2)).unwrap();
+        tick_time.pause();
+        tick_time.pause();
+        assert_eq!(TimeScale::times(2), tick_time.time_scale());
+        tick_time.advance(10).unwrap();
+        tick_time.resume();
+        tick_time.advance(10).unwrap();
+        assert_eq!(20, tick_time.minute());
+
+        // A scale set while paused is applied on resume
+        tick_time.pause();
+        tick_time.set_time_scale(TimeScale::times(3)).unwrap();
+        tick_time.tick();
+        assert_eq!(20, tick_time.minute());
+        tick_time.resume();
+        tick_time.tick();
+        assert_eq!(23, tick_time.minute());
+        tick_time.resume();
+        assert_eq!(TimeScale::times(3), tick_time.time_scale());
+    }
+
+    #[test]
+    fn rewind_while_paused_should_return_events_when_the_date_changes() {
+        let mut tick_time = TickTime::init(0, lunar_options(60, true)).unwrap();
+        tick_time.advance(10).unwrap();
+        tick_time.pause();
+        tick_time.advance(10).unwrap();
+        assert!(tick_time.rewind(5).unwrap().is_none());
+        let event = tick_time.rewind(10).unwrap().unwrap();
+        assert_eq!(TickDirection::Backward, event.direction);
+        assert_eq!(5, tick_time.minute());
+
+        // The time stays stopped at the new date
+        assert!(tick_time.is_paused());
+        assert!(tick_time.tick().is_none());
+        assert!(tick_time.advance(20).unwrap().is_none());
+        assert_eq!((26, 5), (tick_time.current_tick(), tick_time.minute()));
+        tick_time.resume();
+        tick_time.tick();
+        assert_eq!(6, tick_time.minute());
+    }
+
+    #[test]
+    fn rewind_should_drop_the_pauses_after_the_new_tick() {
+        let mut tick_time = TickTime::init(0, lunar_options(1, false)).unwrap();
+        tick_time.advance(100).unwrap();
+        tick_time.pause();
+        tick_time.advance(100).unwrap();
+        tick_time.resume();
+        tick_time.advance(100).unwrap();
+        tick_time.rewind(250).unwrap();
+        assert_eq!(50 * 1000, tick_time.elapsed_milliseconds());
+
+        // The old pause is not replayed
+        tick_time.advance(100).unwrap();
+        assert_eq!(150 * 1000, tick_time.elapsed_milliseconds());
+        assert_eq!(TimeScale::times(1), tick_time.time_scale());
+        tick_time.pause();
+        tick_time.resume();
+        tick_time.advance(100).unwrap();
+        assert_eq!(250 * 1000, tick_time.elapsed_milliseconds());
+    }
+}
+
+#[cfg(test)]
+mod driver_tests {
+    use std::time::Duration;
+    use crate::*;
+    use super::test_helpers::lunar_tick_time;
+    use crate::driver::TickDriver;
+
+    #[test]
+    fn driver_should_accumulate_real_time() {
+        let mut tick_time = lunar_tick_time(60, false);
+        let mut driver = TickDriver::with_ticks_per_second(10, 5).unwrap();
+        assert!(driver.update(&mut tick_time, Duration::from_millis(50)).is_none());
+        assert_eq!(0, tick_time.current_tick());
+        driver.update(&mut tick_time, Duration::from_millis(50));
+        assert_eq!(1, tick_time.current_tick());
+        driver.update(&mut tick_time, Duration::from_millis(275));
+        assert_eq!(3, tick_time.current_tick());
+        assert_eq!(Duration::from_millis(75), driver.accumulated());
+        assert!((driver.alpha() - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn driver_should_cap_the_steps_per_frame() {
+        let mut tick_time = lunar_tick_time(60, false);
+        let mut driver = TickDriver::new(Duration::from_millis(100), 5).unwrap();
+        driver.update(&mut tick_time, Duration::from_millis(1030));
+        assert_eq!(5, tick_time.current_tick());
+        assert_eq!(Duration::from_millis(30), driver.accumulated());
+    }
+
+    #[test]
+    fn driver_should_merge_the_events() {
+        let mut tick_time = lunar_tick_time(60, true);
+        tick_time.advance(58).unwrap();
+        let mut driver = TickDriver::new(Duration::from_millis(10), 10).unwrap();
+        let event = driver.update(&mut tick_time, Duration::from_millis(30)).unwrap();
+        let minute_update = event.minute_update.unwrap();
+        assert_eq!((58, 1), (minute_update.old_value, minute_update.new_value));
+        let hour_update = event.hour_update.unwrap();
+        assert_eq!((0, 1), (hour_update.old_value, hour_update.new_value));
+        assert!(event.day_update.is_none());
+    }
+
+    #[test]
+    fn driver_should_reject_invalid_configuration() {
+        assert_eq!(Some(TickTimeError::ZeroTickDuration), TickDriver::new(Duration::ZERO, 1).err());
+        assert_eq!(Some(TickTimeError::ZeroTickDuration), TickDriver::with_ticks_per_second(0, 1).err());
+        assert_eq!(Some(TickTimeError::ZeroMaxStepsPerFrame), TickDriver::new(Duration::from_millis(1), 0).err());
+    }
+}
+
+#[cfg(test)]
+mod listener_tests {
+    use std::sync::{Arc, Mutex};
+    use crate::*;
+    use super::test_helpers::lunar_tick_time;
+    use crate::event::{TickDirection, TimeUnit};
+
+    #[test]
+    fn listeners_should_be_called_on_their_unit_updates() {
+        let mut tick_time = lunar_tick_time(3600, false);
+        let days = Arc::new(Mutex::new(vec![]));
+        let months = Arc::new(Mutex::new(0));
+        let days_clone = days.clone();
+        tick_time.subscribe(TimeUnit::Day, move |event| {
+            days_clone.lock().unwrap().push(event.day_update.as_ref().unwrap().new_value);
+        });
+        let months_clone = months.clone();
+        tick_time.subscribe(TimeUnit::Month, move |_| *months_clone.lock().unwrap() += 1);
+
+        assert!(tick_time.advance(23).unwrap().is_none());
+        assert!(days.lock().unwrap().is_empty());
+        for _ in 0..24 * 30 {
+            assert!(tick_time.tick().is_none());
+        }
+        assert_eq!(30, days.lock().unwrap().len());
+        assert_eq!(vec![1, 2, 3], days.lock().unwrap()[..3].to_vec());
+        assert_eq!(1, *months.lock().unwrap());
+    }
+
+    #[test]
+    fn listeners_should_be_called_with_the_direction_of_rewinds() {
+        let mut tick_time = lunar_tick_time(3600, false);
+        tick_time.advance(30).unwrap();
+        let directions = Arc::new(Mutex::new(vec![]));
+        let directions_clone = directions.clone();
+        tick_time.subscribe(TimeUnit::Day, move |event| directions_clone.lock().unwrap().push(event.direction));
+
+        tick_time.rewind(5).unwrap();
+        assert!(directions.lock().unwrap().is_empty());
+        tick_time.rewind(5).unwrap();
+        tick_time.set_tick(30).unwrap();
+        assert_eq!(vec![TickDirection::Backward, TickDirection::Forward], *directions.lock().unwrap());
+    }
+
+    #[test]
+    fn listeners_should_only_be_called_on_the_boundaries_of_their_unit() {
+        let mut tick_time = TickTime::init(0, TickTimeOptions {
+            tick_time_type: TickTimeType::Custom {
+                seconds_per_tick: 3600,
+                hours_in_a_day: 1,
+                months_durations: vec![10, 10],
+                seasons_durations: vec![10, 10],
+                week_duration: 7,
+            },
+            compute_events: false,
+            seasons_offset: 5,
+            ..Default::default()
+        }).unwrap();
+        let calls = Arc::new(Mutex::new(vec![]));
+        for unit in [TimeUnit::Week, TimeUnit::Season, TimeUnit::Year] {
+            let calls_clone = calls.clone();
+            tick_time.subscribe(unit, move |event| calls_clone.lock().unwrap().push((unit, event.to_tick)));
+        }
+
+        for _ in 0..40 {
+            tick_time.tick();
+        }
+        let ticks = |unit| calls.lock().unwrap().iter().filter(|(called, _)| *called == unit).map(|(_, tick)| *tick).collect::<Vec<_>>();
+        // Seasons start on the days 5 and 15 of each year, not with the year
+        assert_eq!(vec![5, 15, 25, 35], ticks(TimeUnit::Season));
+        // Weeks are counted from the start of each year
+        assert_eq!(vec![7, 14, 20, 27, 34, 40], ticks(TimeUnit::Week));
+        assert_eq!(vec![20, 40], ticks(TimeUnit::Year));
+    }
+
+    #[test]
+    fn unsubscribed_listeners_should_not_be_called() {
+        let mut tick_time = lunar_tick_time(3600, true);
+        let calls = Arc::new(Mutex::new(0));
+        let calls_clone = calls.clone();
+        let handle = tick_time.subscribe(TimeUnit::Hour, move |_| *calls_clone.lock().unwrap() += 1);
+        assert!(tick_time.tick().is_some());
+        assert!(tick_time.unsubscribe(handle));
+        assert!(!tick_time.unsubscribe(handle));
+        tick_time.tick();
+        assert_eq!(1, *calls.lock().unwrap());
+    }
+
+    #[test]
+    fn listeners_should_not_be_cloned() {
+        let mut tick_time = lunar_tick_time(3600, false);
+        let calls = Arc::new(Mutex::new(0));
+        let calls_clone = calls.clone();
+        tick_time.subscribe(TimeUnit::Hour, move |_| *calls_clone.lock().unwrap() += 1);
+        let mut clone = tick_time.clone();
+        clone.tick();
+        assert_eq!(0, *calls.lock().unwrap());
+        tick_time.tick();
+        assert_eq!(1, *calls.lock().unwrap());
+    }
+
+    #[test]
+    fn listeners_should_not_be_called_while_paused() {
+        let mut tick_time = lunar_tick_time(3600, false);
+        let calls = Arc::new(Mutex::new(0));
+        let calls_clone = calls.clone();
+        tick_time.subscribe(TimeUnit::Second, move |_| *calls_clone.lock().unwrap() += 1);
+        tick_time.pause();
+        tick_time.advance(10).unwrap();
+        assert_eq!(0, *calls.lock().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod alarm_tests {
+    use crate::*;
+    use super::test_helpers::real_tick_time;
+    use crate::alarm::AlarmSchedule;
+    use crate::date::GameDateTime;
+    use crate::duration::GameDuration;
+
+    #[test]
+    fn one_shot_alarms_should_fire_once() {
+        let mut tick_time = real_tick_time(3600, false);
+        let at_tick = tick_time.schedule_alarm(AlarmSchedule::AtTick(2)).unwrap();
+        let harvest = GameDateTime { month: 2, day: 20, week: 11, weekday: 3, ..Default::default() };
+        let at_date = tick_time.schedule_alarm(AlarmSchedule::AtDate(harvest)).unwrap();
+
+        assert!(tick_time.tick().is_none());
+        let event = tick_time.tick().unwrap();
+        assert_eq!(1, event.alarms.len());
+        assert_eq!((at_tick, 2), (event.alarms[0].handle, event.alarms[0].tick));
+        assert_eq!(2, event.alarms[0].date.hour);
+
+        let event = tick_time.advance(24 * 365).unwrap().unwrap();
+        assert_eq!(1, event.alarms.len());
+        assert_eq!((at_date, harvest, 24 * (31 + 29 + 20)), (event.alarms[0].handle, event.alarms[0].date, event.alarms[0].tick));
+        assert!(tick_time.advance(24 * 365).unwrap().is_none());
+    }
+
+    #[test]
+    fn repeating_alarms_should_not_be_missed_during_jumps() {
+        let mut tick_time = real_tick_time(3600, true);
+        tick_time.advance(7).unwrap();
+        let farmer = tick_time.schedule_alarm(AlarmSchedule::DailyAt { hour: 6, minute: 0, second: 0 }).unwrap();
+        let every = tick_time.schedule_alarm(AlarmSchedule::Every(GameDuration::hours(12))).unwrap();
+
+        let event = tick_time.advance(24 * 2).unwrap().unwrap();
+        let fired: Vec<_> = event.alarms.iter().map(|alarm| (alarm.handle, alarm.tick)).collect();
+        assert_eq!(vec![(every, 19), (farmer, 30), (every, 31), (every, 43), (farmer, 54), (every, 55)], fired);
+        assert!(tick_time.tick().unwrap().alarms.is_empty());
+    }
+
+    #[test]
+    fn cancelled_alarms_should_not_fire() {
+        let mut tick_time = real_tick_time(3600, false);
+        let handle = tick_time.schedule_alarm(AlarmSchedule::Every(GameDuration::hours(1))).unwrap();
+        assert_eq!(1, tick_time.tick().unwrap().alarms.len());
+        assert!(tick_time.cancel_alarm(handle));
+        assert!(!tick_time.cancel_alarm(handle));
+        assert!(tick_time.tick().is_none());
+    }
+
+    #[test]
+    fn alarms_should_follow_the_time_scale() {
+        let mut tick_time = real_tick_time(3600, false);
+        tick_time.schedule_alarm(AlarmSchedule::Every(GameDuration::hours(2))).unwrap();
+        tick_time.schedule_alarm(AlarmSchedule::AtTick(3)).unwrap();
+        tick_time.pause();
+        let event = tick_time.advance(5).unwrap().unwrap();
+        assert_eq!(vec![3], event.alarms.iter().map(|alarm| alarm.tick).collect::<Vec<_>>());
+        tick_time.resume();
+        tick_time.set_time_scale(TimeScale::times(2)).unwrap();
+        assert!(tick_time.tick().unwrap().alarms.len() == 1);
+        assert!(tick_time.tick().unwrap().alarms.len() == 1);
+    }
+
+    #[test]
+    fn rewinding_should_not_fire_alarms() {
+        let mut tick_time = real_tick_time(3600, false);
+        tick_time.advance(10).unwrap();
+        tick_time.schedule_alarm(AlarmSchedule::AtTick(5)).unwrap();
+        assert!(tick_time.rewind(2).unwrap().is_none());
+        assert_eq!(5, tick_time.tick().unwrap().alarms[0].tick);
+    }
+
+    #[test]
+    fn invalid_alarms_should_be_rejected() {
+        let mut tick_time = real_tick_time(3600, false);
+        assert_eq!(Err(TickTimeError::CalendarDependentDuration), tick_time.schedule_alarm(AlarmSchedule::Every(GameDuration::months(1))));
+        assert_eq!(Err(TickTimeError::ZeroAlarmInterval), tick_time.schedule_alarm(AlarmSchedule::Every(GameDuration::default())));
+        assert_eq!(
+            Err(TickTimeError::HourOutOfRange { hour: 24, hours_in_a_day: 24 }),
+            tick_time.schedule_alarm(AlarmSchedule::DailyAt { hour: 24, minute: 0, second: 0 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod recurrence_tests {
+    use crate::*;
+    use super::test_helpers::{lunar_tick_time, real_tick_time_at, two_season_custom_options};
+    use crate::recurrence::{RecurrenceDay, RecurrenceRule};
+
+    /// The 1st of January 2000 is a Saturday
+    const EPOCH_2000: TickTimeEpoch = TickTimeEpoch { year: 2000, month: 0, day: 0, hour: 0, minute: 0, second: 0, weekday: 5 };
+
+    #[test]
+    fn first_weekday_of_month_should_follow_month_lengths() {
+        let tick_time = real_tick_time_at(3600, EPOCH_2000);
+        let rule = RecurrenceRule { day: RecurrenceDay::FirstWeekdayOfMonth(0), hour: 9, ..Default::default() };
+        // Monday the 3rd of January, then Monday the 7th of February
+        assert_eq!(Ok(Some(24 * 2 + 9)), rule.next_occurrence_after(&tick_time, 0));
+        assert_eq!(Ok(Some(24 * (31 + 6) + 9)), rule.next_occurrence_after(&tick_time, 24 * 2 + 9));
+    }
+
+    #[test]
+    fn last_day_of_season_should_follow_leap_years() {
+        let tick_time = real_tick_time_at(3600, EPOCH_2000);
+        let rule = RecurrenceRule { day: RecurrenceDay::LastDayOfSeason, ..Default::default() };
+        assert_eq!(Ok(Some(24 * 80)), rule.next_occurrence_after(&tick_time, 0));
+        assert_eq!(Ok(Some(24 * (80 + 92))), rule.next_occurrence_after(&tick_time, 24 * 80));
+        let rule = RecurrenceRule { day: RecurrenceDay::FirstDayOfSeason, ..Default::default() };
+        assert_eq!(Ok(Some(24 * 81)), rule.next_occurrence_after(&tick_time, 0));
+    }
+
+    #[test]
+    fn first_day_of_the_calendar_should_only_start_a_season_on_a_boundary() {
+        let rule = RecurrenceRule { day: RecurrenceDay::FirstDayOfSeason, hour: 9, ..Default::default() };
+        // The winter of the real calendar starts before the year 0
+        let tick_time = real_tick_time_at(3600, Default::default());
+        assert_eq!(Ok(Some(24 * 81 + 9)), rule.next_occurrence_after(&tick_time, 0));
+
+        let tick_time = lunar_tick_time(3600, false);
+        assert_eq!(Ok(Some(9)), rule.next_occurrence_after(&tick_time, 0));
+    }
+
+    #[test]
+    fn year_interval_should_skip_years() {
+        let tick_time = real_tick_time_at(3600, EPOCH_2000);
+        let rule = RecurrenceRule {
+            day: RecurrenceDay::DayOfMonth(0),
+            month: Some(0),
+            year_interval: 3,
+            first_year: 2001,
+            ..Default::default()
+        };
+        assert_eq!(Ok(Some(24 * 366)), rule.next_occurrence_after(&tick_time, 0));
+        assert_eq!(Ok(Some(24 * (366 + 365 * 3))), rule.next_occurrence_after(&tick_time, 24 * 366));
+    }
+
+    #[test]
+    fn distant_first_year_should_be_reached() {
+        let tick_time = TickTime::init(0, TickTimeOptions {
+            tick_time_type: TickTimeType::EarthLike { seconds_per_tick: 3600 * 24, month_type: EarthLikeMonthType::Lunar },
+            compute_events: false,
+            ..Default::default()
+        }).unwrap();
+        let rule = RecurrenceRule { day: RecurrenceDay::DayOfMonth(0), month: Some(0), first_year: 401, ..Default::default() };
+        assert_eq!(Ok(Some(401 * 360)), rule.next_occurrence_after(&tick_time, 0));
+
+        let last_counted_year = usize::MAX / (360 * 24 * 3600);
+        let rule = RecurrenceRule { first_year: last_counted_year, ..rule };
+        assert_eq!(Ok(Some(last_counted_year * 360)), rule.next_occurrence_after(&tick_time, 0));
+        let rule = RecurrenceRule { first_year: usize::MAX, ..rule };
+        assert_eq!(Ok(None), rule.next_occurrence_after(&tick_time, 0));
+        // The year 0 only matches at the tick 0, the next matching year can't be counted
+        let rule = RecurrenceRule { year_interval: usize::MAX, first_year: 0, ..rule };
+        assert_eq!(Ok(None), rule.next_occurrence_after(&tick_time, 0));
+    }
+
+    #[test]
+    fn custom_calendar_rules_should_skip_short_months() {
+        let tick_time = TickTime::init(0, two_season_custom_options()).unwrap();
+        let rule = RecurrenceRule { day: RecurrenceDay::LastDayOfMonth, ..Default::default() };
+        assert_eq!(Ok(Some(12 * 9)), rule.next_occurrence_after(&tick_time, 0));
+        assert_eq!(Ok(Some(12 * 14)), rule.next_occurrence_after(&tick_time, 12 * 9));
+
+        let rule = RecurrenceRule { day: RecurrenceDay::DayOfMonth(7), ..Default::default() };
+        assert_eq!(Ok(Some(12 * 22)), rule.next_occurrence_after(&tick_time, 12 * 7));
+        let rule = RecurrenceRule { day: RecurrenceDay::LastWeekdayOfMonth(3), hour: 6, ..Default::default() };
+        assert_eq!(Ok(Some(12 * 13 + 6)), rule.next_occurrence_after(&tick_time, 12 * 9));
+        let rule = RecurrenceRule { day: RecurrenceDay::DayOfMonth(2), month: Some(1), ..Default::default() };
+        assert_eq!(Ok(Some(12 * 27)), rule.next_occurrence_after(&tick_time, 12 * 12));
+    }
+
+    #[test]
+    fn unreachable_occurrences_should_return_none() {
+        let mut tick_time = TickTime::init(0, two_season_custom_options()).unwrap();
+        let rule = RecurrenceRule { day: RecurrenceDay::DayOfMonth(12), ..Default::default() };
+        assert_eq!(Ok(None), rule.next_occurrence_after(&tick_time, 0));
+
+        tick_time.pause();
+        let rule = RecurrenceRule::default();
+        assert_eq!(Ok(None), rule.next_occurrence_after(&tick_time, 0));
+    }
+
+    #[test]
+    fn invalid_rules_should_be_rejected() {
+        let tick_time = TickTime::init(0, two_season_custom_options()).unwrap();
+        let rule = RecurrenceRule { year_interval: 0, ..Default::default() };
+        assert_eq!(Err(TickTimeError::ZeroYearInterval), rule.next_occurrence_after(&tick_time, 0));
+        let rule = RecurrenceRule { day: RecurrenceDay::Weekday(5), ..Default::default() };
+        assert_eq!(Err(TickTimeError::WeekdayOutOfRange { weekday: 5, week_duration: 5 }), rule.next_occurrence_after(&tick_time, 0));
+        let rule = RecurrenceRule { hour: 12, ..Default::default() };
+        assert_eq!(Err(TickTimeError::HourOutOfRange { hour: 12, hours_in_a_day: 12 }), rule.next_occurrence_after(&tick_time, 0));
+        let rule = RecurrenceRule { month: Some(2), ..Default::default() };
+        assert_eq!(Err(TickTimeError::MonthOutOfRange { month: 2, months_in_year: 2 }), rule.next_occurrence_after(&tick_time, 0));
+    }
+}
+
+#[cfg(test)]
+mod boundary_tests {
+    use crate::*;
+    use crate::event::TimeUnit;
+
+    const UNITS: [TimeUnit; 6] = [TimeUnit::Hour, TimeUnit::Day, TimeUnit::Week, TimeUnit::Month, TimeUnit::Season, TimeUnit::Year];
+
+    fn unit_value(tick_time: &TickTime, unit: TimeUnit) -> (usize, usize, usize, usize) {
+        let (year, month, day) = (tick_time.year(), tick_time.month(), tick_time.day());
+        match unit {
+            TimeUnit::Second => (year, month, day, tick_time.hour() * 3600 + tick_time.minute() * 60 + tick_time.second()),
+            TimeUnit::Minute => (year, month, day, tick_time.hour() * 60 + tick_time.minute()),
+            TimeUnit::Hour => (year, month, day, tick_time.hour()),
+            TimeUnit::Day => (year, month, day, 0),
+            TimeUnit::Week => (year, tick_time.week(), 0, 0),
+            TimeUnit::Month => (year, month, 0, 0),
+            TimeUnit::Season => (tick_time.season(), 0, 0, 0),
+            TimeUnit::Year => (year, 0, 0, 0),
+        }
+    }
+
+    /// Compare `ticks_until_next` with the number of ticks needed to change the unit tick by tick
+    fn assert_boundaries_match_ticking(options: TickTimeOptions, last_tick: usize) {
+        for start in (0..last_tick).step_by(173) {
+            let tick_time = TickTime::init(start, options.clone()).unwrap();
+            for unit in UNITS {
+                let mut ticked = tick_time.clone();
+                let mut ticks = 0;
+                while unit_value(&ticked, unit) == unit_value(&tick_time, unit) {
+                    ticked.tick();
+                    ticks += 1;
+                }
+                assert_eq!(Some(ticks), tick_time.ticks_until_next(unit), "{:?} from the tick {}", unit, start);
+            }
+        }
+    }
+
+    /// Compare the units crossed by updates of various lengths with the next boundaries
+    fn assert_crossed_units_match_boundaries(options: TickTimeOptions, last_tick: usize) {
+        let mut tick_time = TickTime::init(0, TickTimeOptions { compute_events: true, ..options }).unwrap();
+        let mut steps = [1, 7, 23, 61, 200, 911].iter().cycle();
+        while tick_time.current_tick() < last_tick {
+            let (from_tick, step) = (tick_time.current_tick(), *steps.next().unwrap());
+            let event = tick_time.advance(step).unwrap().unwrap();
+            for unit in TimeUnit::ALL {
+                let crossed = tick_time.ticks_until_next_at(from_tick, unit).is_some_and(|ticks| ticks <= step);
+                assert_eq!(crossed, event.crossed(unit), "{:?} from the tick {}", unit, from_tick);
+            }
+        }
+    }
+
+    #[test]
+    fn crossed_units_should_match_the_next_boundaries() {
+        let real = TickTimeType::EarthLike { seconds_per_tick: 3600, month_type: EarthLikeMonthType::Real };
+        let epoch = TickTimeEpoch { year: 1999, month: 5, ..Default::default() };
+        assert_crossed_units_match_boundaries(TickTimeOptions { tick_time_type: real, epoch, ..Default::default() }, 24 * 1500);
+        let lunar = TickTimeType::EarthLike { seconds_per_tick: 1800, month_type: EarthLikeMonthType::Lunar };
+        assert_crossed_units_match_boundaries(TickTimeOptions { tick_time_type: lunar, ..Default::default() }, 48 * 800);
+        let custom = TickTimeType::Custom {
+            seconds_per_tick: 3600,
+            hours_in_a_day: 10,
+            months_durations: vec![10, 5, 8],
+            seasons_durations: vec![6, 9, 8],
+            week_duration: 4,
+        };
+        assert_crossed_units_match_boundaries(TickTimeOptions { tick_time_type: custom, seasons_offset: 4, ..Default::default() }, 10 * 200);
+    }
+
+    #[test]
+    fn real_calendar_boundaries_should_match_ticking() {
+        let tick_time_type = TickTimeType::EarthLike { seconds_per_tick: 3600, month_type: EarthLikeMonthType::Real };
+        let epoch = TickTimeEpoch { year: 1999, month: 5, ..Default::default() };
+        assert_boundaries_match_ticking(TickTimeOptions { tick_time_type, epoch, ..Default::default() }, 24 * 800);
+    }
+
+    #[test]
+    fn lunar_calendar_boundaries_should_match_ticking() {
+        let tick_time_type = TickTimeType::EarthLike { seconds_per_tick: 3600, month_type: EarthLikeMonthType::Lunar };
+        assert_boundaries_match_ticking(TickTimeOptions { tick_time_type, ..Default::default() }, 24 * 400);
+    }
+
+    #[test]
+    fn custom_calendar_boundaries_should_match_ticking() {
+        let tick_time_type = TickTimeType::Custom {
+            seconds_per_tick: 3600,
+            hours_in_a_day: 10,
+            months_durations: vec![10, 5, 8],
+            seasons_durations: vec![6, 9, 8],
+            week_duration: 4,
+        };
+        let options = TickTimeOptions { tick_time_type, seasons_offset: 4, ..Default::default() };
+        assert_boundaries_match_ticking(options, 10 * 60);
+    }
+
+    #[test]
+    fn sub_hour_boundaries_should_follow_the_tick_rate() {
+        let tick_time_type = TickTimeType::EarthLike { seconds_per_tick: 1, month_type: EarthLikeMonthType::Lunar };
+        let epoch = TickTimeEpoch { minute: 59, second: 58, ..Default::default() };
+        let mut options = TickTimeOptions { tick_time_type, epoch, ..Default::default() };
+        options.tick_rate = Some(TickRate::milliseconds_per_tick(400));
+        let tick_time = TickTime::init(1, options).unwrap();
+        assert_eq!(Some(2), tick_time.ticks_until_next(TimeUnit::Second));
+        assert_eq!(Some(4), tick_time.ticks_until_next(TimeUnit::Minute));
+        assert_eq!(Some(4), tick_time.ticks_until_next(TimeUnit::Hour));
+    }
+
+    #[test]
+    fn boundaries_should_be_reached_even_when_the_value_stays_the_same() {
+        let tick_time_type = TickTimeType::EarthLike { seconds_per_tick: 60, month_type: EarthLikeMonthType::Lunar };
+        let mut tick_time = TickTime::init(0, TickTimeOptions { tick_time_type, ..Default::default() }).unwrap();
+        assert_eq!(Some(1), tick_time.ticks_until_next(TimeUnit::Second));
+        tick_time.tick();
+        assert_eq!(0, tick_time.second());
+    }
+
+    #[test]
+    fn boundaries_should_follow_the_time_scale() {
+        let tick_time_type = TickTimeType::EarthLike { seconds_per_tick: 3600, month_type: EarthLikeMonthType::Lunar };
+        let mut tick_time = TickTime::init(0, TickTimeOptions { tick_time_type, ..Default::default() }).unwrap();
+        tick_time.set_time_scale(TimeScale::times(5)).unwrap();
+        assert_eq!(Some(5), tick_time.ticks_until_next(TimeUnit::Day));
+        tick_time.pause();
+        assert_eq!(None, tick_time.ticks_until_next(TimeUnit::Day));
+    }
+
+    #[test]
+    fn single_season_should_have_no_boundary() {
+        let tick_time_type = TickTimeType::Custom {
+            seconds_per_tick: 3600,
+            hours_in_a_day: 10,
+            months_durations: vec![10],
+            seasons_durations: vec![10],
+            week_duration: 4,
+        };
+        let tick_time = TickTime::init(0, TickTimeOptions { tick_time_type, ..Default::default() }).unwrap();
+        assert_eq!(None, tick_time.ticks_until_next(TimeUnit::Season));
+        assert_eq!(Some(100), tick_time.ticks_until_next(TimeUnit::Year));
+    }
+}
+
+#[cfg(test)]
+mod rich_event_tests {
+    use super::test_helpers::{lunar_tick_time, real_tick_time};
+    use crate::date::GameDateTime;
+    use crate::event::TimeUnit;
+
+    #[test]
+    fn event_should_carry_dates_and_ticks() {
+        let mut tick_time = lunar_tick_time(3600, true);
+        tick_time.advance(5).unwrap();
+        let event = tick_time.advance(24 * 31).unwrap().unwrap();
+        assert_eq!(GameDateTime { hour: 5, ..Default::default() }, event.before);
+        assert_eq!(GameDateTime { month: 1, day: 1, hour: 5, week: 4, weekday: 3, ..Default::default() }, event.after);
+        assert_eq!((5, 24 * 31 + 5), (event.from_tick, event.to_tick));
+    }
+
+    #[test]
+    fn changed_units_should_only_hold_different_values() {
+        let mut tick_time = lunar_tick_time(3600, true);
+        let event = tick_time.tick().unwrap();
+        assert_eq!(vec![TimeUnit::Hour], event.changed_units);
+        assert_eq!(vec![TimeUnit::Second, TimeUnit::Minute, TimeUnit::Hour], event.crossed_units);
+
+        let event = tick_time.advance(360 * 24).unwrap().unwrap();
+        assert_eq!(vec![TimeUnit::Year], event.changed_units);
+        assert!(event.week_update.is_some());
+        assert!(!event.has_changed(TimeUnit::Week));
+        assert!(TimeUnit::ALL.iter().all(|unit| event.crossed(*unit)));
+    }
+
+    #[test]
+    fn crossed_units_should_follow_season_boundaries() {
+        let mut tick_time = real_tick_time(3600, true);
+        // From the 20th to the 22nd of December of the year 1
+        tick_time.set_tick(24 * (366 + 353)).unwrap();
+        let event = tick_time.advance(48).unwrap().unwrap();
+        assert!(event.crossed(TimeUnit::Season) && event.has_changed(TimeUnit::Season));
+        assert!(!event.crossed(TimeUnit::Month) && !event.crossed(TimeUnit::Year));
+
+        // The new year doesn't change the season
+        let event = tick_time.advance(24 * 10).unwrap().unwrap();
+        assert!(event.crossed(TimeUnit::Year) && event.crossed(TimeUnit::Month));
+        assert!(!event.crossed(TimeUnit::Season));
+    }
+
+    #[test]
+    fn crossed_units_should_be_computed_backward() {
+        let mut tick_time = lunar_tick_time(3600, true);
+        tick_time.advance(30).unwrap();
+        let event = tick_time.rewind(24).unwrap().unwrap();
+        assert_eq!((30, 6), (event.from_tick, event.to_tick));
+        assert!(event.crossed(TimeUnit::Day) && event.has_changed(TimeUnit::Day));
+        assert!(!event.crossed(TimeUnit::Month));
+        let event = tick_time.rewind(2).unwrap().unwrap();
+        assert!(!event.crossed(TimeUnit::Day));
+    }
+
+    #[test]
+    fn merged_events_should_cover_the_whole_range() {
+        let mut tick_time = lunar_tick_time(3600, true);
+        tick_time.advance(23).unwrap();
+        let first = tick_time.tick().unwrap();
+        let second = tick_time.tick().unwrap();
+        let event = first.merge(second);
+        assert_eq!((23, 25), (event.from_tick, event.to_tick));
+        assert_eq!(GameDateTime { day: 1, hour: 1, weekday: 1, ..Default::default() }, event.after);
+        assert!(event.crossed(TimeUnit::Day));
+        assert_eq!(vec![TimeUnit::Hour, TimeUnit::Day], event.changed_units);
+    }
+}
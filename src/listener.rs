@@ -0,0 +1,73 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::event::{TickTimeEvent, TimeUnit};
+use crate::TickTime;
+
+/// A callback called with the event of the update
+type Listener = Box<dyn FnMut(&TickTimeEvent) + Send + Sync>;
+
+/// Identifies a listener subscribed to a `TickTime`, to unsubscribe it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListenerHandle(usize);
+
+/// Listeners subscribed to a `TickTime`, in the order of subscription
+#[derive(Default)]
+pub(crate) struct ListenerRegistry {
+    /// Identifier given to the next listener
+    next_id: usize,
+    listeners: Vec<(ListenerHandle, TimeUnit, Listener)>,
+}
+
+impl ListenerRegistry {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+
+    /// Call the listeners of the units whose boundaries were crossed by the event
+    pub(crate) fn notify(&mut self, event: &TickTimeEvent) {
+        for (_, unit, listener) in self.listeners.iter_mut() {
+            if event.crossed(*unit) {
+                listener(event);
+            }
+        }
+    }
+}
+
+/// Listeners are not cloned, the clone of a `TickTime` starts without listener
+impl Clone for ListenerRegistry {
+    fn clone(&self) -> Self {
+        ListenerRegistry::default()
+    }
+}
+
+impl fmt::Debug for ListenerRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListenerRegistry").field("listeners", &self.listeners.len()).finish()
+    }
+}
+
+impl TickTime {
+    /// Call `listener` with the event of each update crossing a boundary of the given unit,
+    /// like a new day for `TimeUnit::Day`. Events are computed for the listeners even when `compute_events`
+    /// is disabled. Moving backward, with `rewind` or `set_tick`, also calls the listeners of the crossed
+    /// units, the event then has a `TickDirection::Backward` direction. The returned handle unsubscribes the listener.
+    pub fn subscribe<F>(&mut self, unit: TimeUnit, listener: F) -> ListenerHandle
+    where
+        F: FnMut(&TickTimeEvent) + Send + Sync + 'static,
+    {
+        let registry = &mut self.listeners;
+        let handle = ListenerHandle(registry.next_id);
+        registry.next_id += 1;
+        registry.listeners.push((handle, unit, Box::new(listener)));
+        handle
+    }
+
+    /// Remove a listener. Returns false if it was already unsubscribed.
+    pub fn unsubscribe(&mut self, handle: ListenerHandle) -> bool {
+        let listeners = &mut self.listeners.listeners;
+        let count = listeners.len();
+        listeners.retain(|(listener_handle, ..)| *listener_handle != handle);
+        listeners.len() != count
+    }
+}
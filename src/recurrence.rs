@@ -0,0 +1,167 @@
+use crate::boundary::season_boundaries;
+use crate::date::GameDateTime;
+use crate::error::TickTimeError;
+use crate::{
+    compute_values, get_days_before_year, get_hours_in_a_day, get_months_durations_of_year, get_week_duration,
+    verify_date_values, TickTime, TickTimeOptions, TickTimeType, TickTimeValue,
+};
+
+/// Days of the calendar matched by a `RecurrenceRule`. Values are zero based.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecurrenceDay {
+    /// Every day
+    #[default]
+    EveryDay,
+    /// Every given day of the week
+    Weekday(usize),
+    /// The given day of each month. Months too short to have this day are skipped.
+    DayOfMonth(usize),
+    /// The last day of each month
+    LastDayOfMonth,
+    /// The first given day of the week of each month, like the first Monday
+    FirstWeekdayOfMonth(usize),
+    /// The last given day of the week of each month, like the last Friday
+    LastWeekdayOfMonth(usize),
+    /// The first day of each season
+    FirstDayOfSeason,
+    /// The last day of each season
+    LastDayOfSeason,
+}
+
+/// A rule matching dates of the calendar, like "the first Monday of every month at 09:00"
+/// or "the last day of each season, every 3rd year".
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecurrenceRule {
+    /// Days of the matched months
+    pub day: RecurrenceDay,
+    /// Only this month of the year matches when set, every month otherwise
+    pub month: Option<usize>,
+    /// Hour of the occurrences
+    pub hour: usize,
+    /// Minute of the occurrences
+    pub minute: usize,
+    /// Second of the occurrences
+    pub second: usize,
+    /// Only one year every `year_interval` years matches, counted from `first_year`. Should be minimum 1.
+    pub year_interval: usize,
+    /// First year matching the rule
+    pub first_year: usize,
+}
+
+impl Default for RecurrenceRule {
+    /// Every day at 00:00:00
+    fn default() -> Self {
+        RecurrenceRule { day: Default::default(), month: None, hour: 0, minute: 0, second: 0, year_interval: 1, first_year: 0 }
+    }
+}
+
+impl RecurrenceRule {
+    /// Return the first tick after `tick` at which the calendar of `tick_time` reaches an occurrence
+    /// of the rule, following its time scales. Returns None when no occurrence can be reached,
+    /// because the rule matches no day of the calendar, the time scale is 0 or the occurrences
+    /// are too far to be represented by a tick. Returns an error when the date of `tick` is too far to be counted.
+    pub fn next_occurrence_after(&self, tick_time: &TickTime, tick: usize) -> Result<Option<usize>, TickTimeError> {
+        let tick_time_type = &tick_time.options.tick_time_type;
+        self.verify(tick_time_type)?;
+        let day_seconds = get_hours_in_a_day(tick_time_type) * 3600;
+        let day_values = |total_days: usize| compute_values(&tick_time.options, total_days * day_seconds);
+
+        let mut total_days = tick_time.tick_total_seconds(tick)? / day_seconds;
+        let mut year = day_values(total_days).year;
+        // Weekdays and leap years repeat after this number of years from the first matching one,
+        // no occurrence is found after it
+        let first_matching_year = if self.matches_year(year) { Some(year) } else { self.next_matching_year(year) };
+        let last_year = match first_matching_year {
+            Some(first_matching_year) => {
+                first_matching_year.saturating_add(self.year_interval.saturating_mul(get_week_duration(tick_time_type).max(400)))
+            }
+            None => return Ok(None),
+        };
+
+        while year <= last_year {
+            if !self.matches_year(year) {
+                match self.next_matching_year(year) {
+                    Some(next_year) => year = next_year,
+                    None => return Ok(None),
+                }
+                match get_days_before_year(tick_time_type, year).filter(|year_start| year_start.checked_mul(day_seconds).is_some()) {
+                    Some(year_start) => total_days = total_days.max(year_start),
+                    None => return Ok(None),
+                }
+                continue;
+            }
+            let values = day_values(total_days);
+            if values.year != year {
+                year = values.year;
+                continue;
+            }
+            if self.matches(&tick_time.options, total_days, day_values) {
+                let date = GameDateTime { hour: self.hour, minute: self.minute, second: self.second, ..values.date() };
+                match tick_time.tick_for(&date) {
+                    Ok(occurrence) if occurrence > tick => return Ok(Some(occurrence)),
+                    Err(TickTimeError::DateUnreachable) | Err(TickTimeError::TickOverflow) => return Ok(None),
+                    _ => {}
+                }
+            }
+            total_days += 1;
+        }
+        Ok(None)
+    }
+
+    fn verify(&self, tick_time_type: &TickTimeType) -> Result<(), TickTimeError> {
+        if self.year_interval == 0 {
+            return Err(TickTimeError::ZeroYearInterval);
+        }
+        let date = GameDateTime { month: self.month.unwrap_or(0), hour: self.hour, minute: self.minute, second: self.second, ..Default::default() };
+        verify_date_values(tick_time_type, &date)?;
+        let week_duration = get_week_duration(tick_time_type);
+        match self.day {
+            RecurrenceDay::Weekday(weekday)
+            | RecurrenceDay::FirstWeekdayOfMonth(weekday)
+            | RecurrenceDay::LastWeekdayOfMonth(weekday) if weekday >= week_duration => {
+                Err(TickTimeError::WeekdayOutOfRange { weekday, week_duration })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the year matches the interval
+    fn matches_year(&self, year: usize) -> bool {
+        year >= self.first_year && (year - self.first_year) % self.year_interval == 0
+    }
+
+    /// First year matching the interval after `year`, None when it is too far to be counted
+    fn next_matching_year(&self, year: usize) -> Option<usize> {
+        if year < self.first_year {
+            return Some(self.first_year);
+        }
+        year.checked_add(self.year_interval - (year - self.first_year) % self.year_interval)
+    }
+
+    /// Whether the day `total_days` after the beginning of the calendar matches the rule
+    fn matches(&self, options: &TickTimeOptions, total_days: usize, day_values: impl Fn(usize) -> TickTimeValue) -> bool {
+        let tick_time_type = &options.tick_time_type;
+        let values = day_values(total_days);
+        let days_in_month = get_months_durations_of_year(tick_time_type, values.year)[values.month];
+        let week_duration = get_week_duration(tick_time_type);
+        if self.month.is_some_and(|month| month != values.month) {
+            return false;
+        }
+        match self.day {
+            RecurrenceDay::EveryDay => true,
+            RecurrenceDay::Weekday(weekday) => values.weekday == weekday,
+            RecurrenceDay::DayOfMonth(day) => values.day == day,
+            RecurrenceDay::LastDayOfMonth => values.day + 1 == days_in_month,
+            RecurrenceDay::FirstWeekdayOfMonth(weekday) => values.weekday == weekday && values.day < week_duration,
+            RecurrenceDay::LastWeekdayOfMonth(weekday) => values.weekday == weekday && values.day + week_duration >= days_in_month,
+            RecurrenceDay::FirstDayOfSeason => match total_days.checked_sub(1) {
+                Some(previous_day) => day_values(previous_day).season != values.season,
+                // The first day of the calendar starts a season only when a season changes on it
+                None => season_boundaries(options, 0).contains(&0),
+            },
+            RecurrenceDay::LastDayOfSeason => day_values(total_days + 1).season != values.season,
+        }
+    }
+}